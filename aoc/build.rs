@@ -0,0 +1,28 @@
+//! Generates a test for every entry in the answers file, so each recorded
+//! answer is checked by `cargo test`.
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::PathBuf;
+
+fn main() {
+    let answers = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap()).join("../answers.tsv");
+    println!("cargo::rerun-if-changed={}", answers.display());
+
+    let contents = fs::read_to_string(&answers).expect("Failed to read answers file");
+    let mut tests = String::new();
+    for line in contents.lines().skip(1).filter(|l| !l.trim().is_empty()) {
+        let fields: Vec<&str> = line.split('\t').collect();
+        let [day, part, answer] = fields[..] else {
+            panic!("Malformed answers entry: {:?}", line);
+        };
+        writeln!(
+            tests,
+            "#[test]\nfn day_{day}_part_{part}() {{\n    check({day}, {part}, {answer:?});\n}}\n"
+        )
+        .unwrap();
+    }
+
+    let out = PathBuf::from(env::var("OUT_DIR").unwrap()).join("answer_tests.rs");
+    fs::write(out, tests).expect("Failed to write generated tests");
+}
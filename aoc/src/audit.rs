@@ -0,0 +1,52 @@
+use std::io::{self, Write};
+
+use day_2::{InvalidId, RepeatMode};
+
+const HEADER: &str = "part,range_start,range_end,id,unit,repeats";
+
+/// Repeat mode each of day 2's parts counts as invalid
+pub fn mode(part: u8) -> RepeatMode {
+    match part {
+        1 => RepeatMode::Twice,
+        _ => RepeatMode::Multi,
+    }
+}
+
+/// Write the invalid IDs found for each part as CSV, one row per ID, as they
+/// are found
+pub fn write_csv(
+    mut out: impl Write,
+    found: impl IntoIterator<Item = (u8, InvalidId)>,
+) -> io::Result<()> {
+    writeln!(out, "{}", HEADER)?;
+    for (part, invalid) in found {
+        writeln!(
+            out,
+            "{},{},{},{},{},{}",
+            part, invalid.range.0, invalid.range.1, invalid.id, invalid.unit, invalid.repeats
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_csv() {
+        let ranges = [(95, 115)];
+        let found = [1, 2].into_iter().flat_map(|part| {
+            day_2::find_invalid_ids(&ranges, mode(part)).map(move |invalid| (part, invalid))
+        });
+        let mut out = Vec::new();
+        write_csv(&mut out, found).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "part,range_start,range_end,id,unit,repeats\n\
+             1,95,115,99,9,2\n\
+             2,95,115,99,9,2\n\
+             2,95,115,111,1,3\n"
+        );
+    }
+}
@@ -0,0 +1,216 @@
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+use std::fs;
+use std::hint::black_box;
+use std::io;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use common::{ParseError, Solution};
+
+/// A step of solving a day that is timed on its own
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Phase {
+    const ALL: [Phase; 3] = [Phase::Parse, Phase::Part1, Phase::Part2];
+
+    fn from_name(name: &str) -> Option<Self> {
+        Phase::ALL.into_iter().find(|p| p.to_string() == name)
+    }
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Phase::Parse => "parse",
+            Phase::Part1 => "part1",
+            Phase::Part2 => "part2",
+        })
+    }
+}
+
+/// Summary of the timings of one phase across every iteration
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "Can't summarise zero samples");
+        samples.sort();
+        let n = samples.len();
+        let median = if n.is_multiple_of(2) {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        } else {
+            samples[n / 2]
+        };
+        let p95 = samples[(n * 95).div_ceil(100) - 1];
+        Self {
+            min: samples[0],
+            median,
+            p95,
+        }
+    }
+}
+
+/// Timings for every phase of one day
+pub struct DayBench {
+    pub day: u8,
+    pub phases: Vec<(Phase, Stats)>,
+}
+
+/// Parse and solve both parts `iterations` times, timing each phase separately
+pub fn bench_day<S: Solution>(input: &str, iterations: usize) -> Result<DayBench, ParseError> {
+    let mut samples: HashMap<Phase, Vec<Duration>> = HashMap::new();
+    let mut record =
+        |phase, start: Instant| samples.entry(phase).or_default().push(start.elapsed());
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let parsed = S::parse(black_box(input))?;
+        record(Phase::Parse, start);
+
+        let start = Instant::now();
+        black_box(S::part1(&parsed));
+        record(Phase::Part1, start);
+
+        let start = Instant::now();
+        black_box(S::part2(&parsed));
+        record(Phase::Part2, start);
+    }
+
+    let phases = Phase::ALL
+        .into_iter()
+        .map(|phase| {
+            (
+                phase,
+                Stats::from_samples(samples.remove(&phase).unwrap_or_default()),
+            )
+        })
+        .collect();
+    Ok(DayBench {
+        day: S::DAY,
+        phases,
+    })
+}
+
+/// Previously recorded timings, keyed by day and phase
+pub type Baseline = HashMap<(u8, Phase), Stats>;
+
+const BASELINE_HEADER: &str = "day\tphase\tmin_ns\tmedian_ns\tp95_ns";
+
+pub fn read_baseline(path: &Path) -> io::Result<Baseline> {
+    let invalid = |line: &str| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("malformed baseline entry: {:?}", line),
+        )
+    };
+    let nanos = |s: &str| s.parse::<u64>().map(Duration::from_nanos).ok();
+
+    let mut baseline = Baseline::new();
+    for line in fs::read_to_string(path)?.lines().skip(1) {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let fields: Vec<&str> = line.split('\t').collect();
+        let [day, phase, min, median, p95] = fields[..] else {
+            return Err(invalid(line));
+        };
+        let entry = (|| {
+            let key = (day.parse().ok()?, Phase::from_name(phase)?);
+            let stats = Stats {
+                min: nanos(min)?,
+                median: nanos(median)?,
+                p95: nanos(p95)?,
+            };
+            Some((key, stats))
+        })()
+        .ok_or_else(|| invalid(line))?;
+        baseline.insert(entry.0, entry.1);
+    }
+    Ok(baseline)
+}
+
+pub fn write_baseline(path: &Path, benches: &[DayBench]) -> io::Result<()> {
+    let mut out = String::from(BASELINE_HEADER);
+    out.push('\n');
+    for bench in benches {
+        for (phase, stats) in &bench.phases {
+            out.push_str(&format!(
+                "{}\t{}\t{}\t{}\t{}\n",
+                bench.day,
+                phase,
+                stats.min.as_nanos(),
+                stats.median.as_nanos(),
+                stats.p95.as_nanos()
+            ));
+        }
+    }
+    fs::write(path, out)
+}
+
+/// Relative change of the median against the baseline, in percent
+pub fn median_change(current: &Stats, baseline: &Stats) -> f64 {
+    let baseline = baseline.median.as_nanos() as f64;
+    if baseline == 0.0 {
+        return 0.0;
+    }
+    (current.median.as_nanos() as f64 - baseline) / baseline * 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&v| Duration::from_millis(v)).collect()
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(ms(&[5, 1, 4, 2, 3]));
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.p95, Duration::from_millis(5));
+
+        let stats = Stats::from_samples(ms(&(1..=100).collect::<Vec<_>>()));
+        assert_eq!(stats.median, Duration::from_micros(50_500));
+        assert_eq!(stats.p95, Duration::from_millis(95));
+    }
+
+    #[test]
+    fn test_baseline_round_trip() {
+        let path = std::env::temp_dir().join(format!("aoc-baseline-{}.tsv", std::process::id()));
+        let stats = Stats::from_samples(ms(&[3, 1, 2]));
+        let bench = DayBench {
+            day: 4,
+            phases: vec![(Phase::Parse, stats), (Phase::Part2, stats)],
+        };
+        write_baseline(&path, &[bench]).unwrap();
+        let baseline = read_baseline(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(baseline.len(), 2);
+        assert_eq!(baseline[&(4, Phase::Part2)], stats);
+    }
+
+    #[test]
+    fn test_median_change() {
+        let stats = |median| Stats {
+            min: Duration::ZERO,
+            median: Duration::from_millis(median),
+            p95: Duration::ZERO,
+        };
+        assert_eq!(median_change(&stats(150), &stats(100)), 50.0);
+        assert_eq!(median_change(&stats(50), &stats(100)), -50.0);
+    }
+}
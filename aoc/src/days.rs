@@ -0,0 +1,29 @@
+use std::path::PathBuf;
+
+/// Every day with a solution, in order
+pub const DAYS: [u8; 6] = [1, 2, 3, 4, 5, 6];
+
+/// Calls a function generic over `Solution` with the type for a given day,
+/// evaluating to `None` when the day has no solution.
+macro_rules! with_day {
+    ($day:expr, $($f:ident)::+($($arg:expr),* $(,)?)) => {
+        match $day {
+            1 => Some($($f)::+::<day_1::Day1>($($arg),*)),
+            2 => Some($($f)::+::<day_2::Day2>($($arg),*)),
+            3 => Some($($f)::+::<day_3::Day3>($($arg),*)),
+            4 => Some($($f)::+::<day_4::Day4>($($arg),*)),
+            5 => Some($($f)::+::<day_5::Day5>($($arg),*)),
+            6 => Some($($f)::+::<day_6::Day6>($($arg),*)),
+            _ => None,
+        }
+    };
+}
+pub(crate) use with_day;
+
+/// Input checked in alongside each day's crate
+pub fn default_input(day: u8) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("day-{}", day))
+        .join("input.txt")
+}
@@ -0,0 +1,279 @@
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Args, Parser, Subcommand};
+use common::cli::Verbosity;
+use common::output::{Format, Printer};
+use common::{Part, Solution};
+
+mod audit;
+mod bench;
+mod days;
+mod verify;
+
+use days::with_day;
+
+/// Advent of Code 2025 runner
+#[derive(Parser)]
+#[command(name = "aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    #[command(flatten)]
+    verbosity: Verbosity,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day, or every day when no day is given
+    Run(RunArgs),
+    /// Time parsing and each part, comparing against a recorded baseline
+    Bench(BenchArgs),
+    /// Check every day against its recorded answers
+    Verify(VerifyArgs),
+    /// List day 2's invalid IDs as CSV, along with the pattern each one repeats
+    Audit(AuditArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Day to run
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=6))]
+    day: Option<u8>,
+
+    /// Part to run
+    #[arg(short, long, value_enum, default_value_t = Part::Both)]
+    part: Part,
+
+    /// Puzzle input, defaults to the day's input.txt
+    #[arg(short, long, requires = "day")]
+    input: Option<PathBuf>,
+
+    /// How answers are written
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Day to benchmark, every day is benchmarked when omitted
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=6))]
+    day: Option<u8>,
+
+    /// Times each day is parsed and solved
+    #[arg(short = 'n', long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+    iterations: u64,
+
+    /// Timings to compare against, written on the first run
+    #[arg(short, long, default_value = "bench-baseline.tsv")]
+    baseline: PathBuf,
+
+    /// Overwrite the baseline with this run's timings
+    #[arg(long)]
+    save: bool,
+
+    /// Slowdown of the median, in percent, reported as a regression
+    #[arg(short, long, default_value_t = 10.0)]
+    threshold: f64,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Recorded answers, a tab separated table of day, part and answer
+    #[arg(short, long, default_value_os_t = verify::default_answers())]
+    answers: PathBuf,
+}
+
+#[derive(Args)]
+struct AuditArgs {
+    /// Part whose invalid IDs are listed
+    #[arg(short, long, value_enum, default_value_t = Part::Both)]
+    part: Part,
+
+    /// Puzzle input, defaults to day 2's input.txt
+    #[arg(short, long, default_value_os_t = days::default_input(day_2::Day2::DAY))]
+    input: PathBuf,
+
+    /// File to write the CSV to, instead of stdout
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    cli.verbosity.init_logging();
+    match cli.command {
+        Command::Run(args) => run(args),
+        Command::Bench(args) => bench(args),
+        Command::Verify(args) => verify(args),
+        Command::Audit(args) => audit(args),
+    }
+}
+
+fn run(args: RunArgs) -> ExitCode {
+    let days = match args.day {
+        Some(day) => vec![day],
+        None => days::DAYS.to_vec(),
+    };
+
+    let mut printer = Printer::new(args.format);
+    for day in days {
+        let day_args = common::cli::Args {
+            part: args.part,
+            input: args
+                .input
+                .clone()
+                .unwrap_or_else(|| days::default_input(day)),
+            format: args.format,
+        };
+        let exit = with_day!(day, common::cli::run(&day_args, &mut printer)).expect("Unknown day");
+        if exit != ExitCode::SUCCESS {
+            return exit;
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+fn bench(args: BenchArgs) -> ExitCode {
+    let days = match args.day {
+        Some(day) => vec![day],
+        None => days::DAYS.to_vec(),
+    };
+    let baseline = if args.baseline.exists() {
+        match bench::read_baseline(&args.baseline) {
+            Ok(baseline) => Some(baseline),
+            Err(e) => {
+                eprintln!("error: failed to read {}: {}", args.baseline.display(), e);
+                return ExitCode::FAILURE;
+            }
+        }
+    } else {
+        None
+    };
+
+    let mut benches = Vec::new();
+    let mut regressions = 0;
+    for day in days {
+        let path = days::default_input(day);
+        let input = match common::read_input(&path) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("error: {}", e);
+                return ExitCode::FAILURE;
+            }
+        };
+        let result = with_day!(day, bench::bench_day(&input, args.iterations as usize))
+            .expect("Unknown day");
+        let day_bench = match result {
+            Ok(day_bench) => day_bench,
+            Err(e) => {
+                eprintln!("{}", e.with_file(&path).render(&input));
+                return ExitCode::FAILURE;
+            }
+        };
+
+        for (phase, stats) in &day_bench.phases {
+            let mut line = format!(
+                "Day {} {:<5}  min {:>12?}  median {:>12?}  p95 {:>12?}",
+                day, phase, stats.min, stats.median, stats.p95
+            );
+            if let Some(previous) = baseline.as_ref().and_then(|b| b.get(&(day, *phase))) {
+                let change = bench::median_change(stats, previous);
+                line.push_str(&format!("  {:+.1}%", change));
+                if change > args.threshold {
+                    line.push_str(" REGRESSION");
+                    regressions += 1;
+                }
+            }
+            println!("{}", line);
+        }
+        benches.push(day_bench);
+    }
+
+    if baseline.is_none() || args.save {
+        if let Err(e) = bench::write_baseline(&args.baseline, &benches) {
+            eprintln!("error: failed to write {}: {}", args.baseline.display(), e);
+            return ExitCode::FAILURE;
+        }
+        println!("Baseline written to {}", args.baseline.display());
+    }
+
+    if regressions > 0 {
+        eprintln!(
+            "{} phase(s) slower than the baseline by more than {}%",
+            regressions, args.threshold
+        );
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+
+fn verify(args: VerifyArgs) -> ExitCode {
+    let answers = match verify::read_answers(&args.answers) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("error: failed to read {}: {}", args.answers.display(), e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut failures = 0;
+    for expected in &answers {
+        let label = format!("Day {} part {}", expected.day, expected.part);
+        match verify::actual(expected) {
+            Ok(actual) if actual == expected.answer => println!("{}: ok ({})", label, actual),
+            Ok(actual) => {
+                println!(
+                    "{}: MISMATCH expected {}, got {}",
+                    label, expected.answer, actual
+                );
+                failures += 1;
+            }
+            Err(e) => {
+                println!("{}: ERROR\n{}", label, e);
+                failures += 1;
+            }
+        }
+    }
+
+    if failures > 0 {
+        eprintln!("{} of {} answers failed", failures, answers.len());
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+
+fn audit(args: AuditArgs) -> ExitCode {
+    let day_args = common::cli::Args {
+        part: args.part,
+        input: args.input,
+        format: Format::Text,
+    };
+    let ranges = match common::cli::load::<day_2::Day2>(&day_args) {
+        Ok(ranges) => ranges,
+        Err(code) => return code,
+    };
+    let found = args.part.numbers().iter().flat_map(|&part| {
+        day_2::find_invalid_ids(&ranges, audit::mode(part)).map(move |invalid| (part, invalid))
+    });
+
+    let written = match &args.output {
+        Some(path) => File::create(path)
+            .and_then(|file| {
+                let mut out = BufWriter::new(file);
+                audit::write_csv(&mut out, found)?;
+                out.flush()
+            })
+            .map_err(|e| format!("failed to write {}: {}", path.display(), e)),
+        None => audit::write_csv(io::stdout().lock(), found)
+            .map_err(|e| format!("failed to write CSV: {}", e)),
+    };
+    if let Err(e) = written {
+        eprintln!("error: {}", e);
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
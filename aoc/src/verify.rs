@@ -0,0 +1,93 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use common::{Part, Solved};
+
+use crate::days::{self, with_day};
+
+/// A known-good answer for one part of a day
+#[derive(Debug, PartialEq)]
+pub struct Expected {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+}
+
+/// Answers recorded alongside the workspace
+pub fn default_answers() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("answers.tsv")
+}
+
+/// Read a tab separated `day`, `part`, `answer` table, skipping its header
+pub fn read_answers(path: &Path) -> io::Result<Vec<Expected>> {
+    let invalid = |line: &str| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("malformed answers entry: {:?}", line),
+        )
+    };
+    fs::read_to_string(path)?
+        .lines()
+        .skip(1)
+        .filter(|l| !l.trim().is_empty())
+        .map(|line| {
+            let fields: Vec<&str> = line.split('\t').collect();
+            let [day, part, answer] = fields[..] else {
+                return Err(invalid(line));
+            };
+            Ok(Expected {
+                day: day.parse().map_err(|_| invalid(line))?,
+                part: part.parse().map_err(|_| invalid(line))?,
+                answer: answer.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Solve the expected part of a day against its checked-in input, returning
+/// the answer actually produced
+pub fn actual(expected: &Expected) -> Result<String, String> {
+    let path = days::default_input(expected.day);
+    let input = common::read_input(&path).map_err(|e| e.to_string())?;
+    let part = match expected.part {
+        1 => Part::One,
+        2 => Part::Two,
+        part => return Err(format!("unknown part {}", part)),
+    };
+    let solved: Solved = with_day!(expected.day, common::solve(&input, part))
+        .ok_or_else(|| format!("unknown day {}", expected.day))?
+        .map_err(|e| e.with_file(&path).render(&input))?;
+    Ok(solved.answers[0].value.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(day: u8, part: u8, answer: &str) {
+        let expected = Expected {
+            day,
+            part,
+            answer: answer.to_string(),
+        };
+        assert_eq!(actual(&expected).unwrap(), answer);
+    }
+
+    include!(concat!(env!("OUT_DIR"), "/answer_tests.rs"));
+
+    #[test]
+    fn test_read_answers() {
+        let answers = read_answers(&default_answers()).unwrap();
+        assert_eq!(
+            answers[0],
+            Expected {
+                day: 1,
+                part: 1,
+                answer: "1158".to_string()
+            }
+        );
+    }
+}
@@ -0,0 +1,141 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{ArgAction, CommandFactory, FromArgMatches, Parser};
+use log::LevelFilter;
+
+use crate::output::{Format, Printer};
+use crate::{ParseError, Part, Solution, read_input, solve};
+
+/// Options shared by every day's binary
+#[derive(clap::Args, Clone, Debug)]
+pub struct Args {
+    /// Part to solve
+    #[arg(short, long, value_enum, default_value_t = Part::Both)]
+    pub part: Part,
+
+    /// Puzzle input
+    #[arg(short, long, default_value = "input.txt")]
+    pub input: PathBuf,
+
+    /// How answers are written
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    pub format: Format,
+}
+
+/// How much logging to show. Warnings are shown by default, `RUST_LOG` takes
+/// precedence when set.
+#[derive(clap::Args, Clone, Copy, Debug, Default)]
+pub struct Verbosity {
+    /// Log more, repeat for more detail (-v info, -vv debug, -vvv trace)
+    #[arg(short, long, action = ArgAction::Count, global = true, conflicts_with = "quiet")]
+    pub verbose: u8,
+
+    /// Log less, repeat to silence errors too
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    pub quiet: u8,
+}
+
+impl Verbosity {
+    pub fn level(&self) -> LevelFilter {
+        match (self.verbose, self.quiet) {
+            (0, 0) => LevelFilter::Warn,
+            (0, 1) => LevelFilter::Error,
+            (0, _) => LevelFilter::Off,
+            (1, _) => LevelFilter::Info,
+            (2, _) => LevelFilter::Debug,
+            _ => LevelFilter::Trace,
+        }
+    }
+
+    pub fn init_logging(&self) {
+        env_logger::Builder::new()
+            .filter_level(self.level())
+            .parse_default_env()
+            .init();
+    }
+}
+
+#[derive(Parser)]
+struct Cli {
+    #[command(flatten)]
+    args: Args,
+
+    #[command(flatten)]
+    verbosity: Verbosity,
+}
+
+/// Entry point for a day's binary
+pub fn main<S: Solution>() -> ExitCode {
+    let matches = Cli::command()
+        .about(format!("Solve day {} of Advent of Code 2025", S::DAY))
+        .get_matches();
+    let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+    cli.verbosity.init_logging();
+    let args = cli.args;
+    run::<S>(&args, &mut Printer::new(args.format))
+}
+
+/// Read and parse the input described by `args`, reporting why it couldn't be
+/// used on stderr. Useful for binaries that do more than solve the two parts.
+pub fn load<S: Solution>(args: &Args) -> Result<S::Input, ExitCode> {
+    load_with(args, S::parse)
+}
+
+/// Read the input described by `args` like [`load`], but parse it with `parse`
+/// rather than the day's own parser
+pub fn load_with<T>(
+    args: &Args,
+    parse: impl FnOnce(&str) -> Result<T, ParseError>,
+) -> Result<T, ExitCode> {
+    let input = read_input(&args.input).map_err(|e| {
+        eprintln!("error: {}", e);
+        ExitCode::FAILURE
+    })?;
+    parse(&input).map_err(|e| {
+        eprintln!("{}", e.with_file(&args.input).render(&input));
+        ExitCode::FAILURE
+    })
+}
+
+/// Solve a day as described by `args`, printing its answers or a diagnostic
+/// explaining why its input couldn't be used
+pub fn run<S: Solution>(args: &Args, printer: &mut Printer) -> ExitCode {
+    let input = match read_input(&args.input) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    match solve::<S>(&input, args.part) {
+        Ok(solved) => {
+            printer.print(&solved);
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{}", e.with_file(&args.input).render(&input));
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verbosity_level() {
+        let level = |args: &[&str]| {
+            Cli::try_parse_from([&["day"], args].concat())
+                .unwrap()
+                .verbosity
+                .level()
+        };
+        assert_eq!(level(&[]), LevelFilter::Warn);
+        assert_eq!(level(&["-v"]), LevelFilter::Info);
+        assert_eq!(level(&["-vvv"]), LevelFilter::Trace);
+        assert_eq!(level(&["-qq"]), LevelFilter::Off);
+        assert!(Cli::try_parse_from(["day", "-v", "-q"]).is_err());
+    }
+}
@@ -0,0 +1,285 @@
+use std::fmt::{self, Display};
+use std::io;
+use std::num::ParseIntError;
+use std::path::{Path, PathBuf};
+
+/// Anything that can go wrong loading and parsing a puzzle input
+#[derive(Debug)]
+pub enum Error {
+    /// Reading failed, along with the file being read when known
+    Io(Option<PathBuf>, io::Error),
+    Parse(ParseError),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(Some(path), e) => write!(f, "failed to read {}: {}", path.display(), e),
+            Error::Io(None, e) => write!(f, "failed to read input: {}", e),
+            Error::Parse(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(_, e) => Some(e),
+            Error::Parse(e) => Some(e),
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(None, e)
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Self {
+        Error::Parse(e)
+    }
+}
+
+/// Why a piece of input was rejected
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseErrorKind {
+    /// Found something other than what the puzzle allows at this point
+    Unexpected { expected: &'static str },
+    /// Ran out of input (or of a line) before finding a required token
+    Missing { expected: &'static str },
+    /// A token looked like a number but couldn't be read as one
+    InvalidNumber(ParseIntError),
+    /// A token had fewer than `min` of what the puzzle needs
+    TooShort { expected: &'static str, min: usize },
+}
+
+impl Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::Unexpected { expected } => write!(f, "expected {}", expected),
+            ParseErrorKind::Missing { expected } => write!(f, "missing {}", expected),
+            ParseErrorKind::InvalidNumber(e) => write!(f, "invalid number ({})", e),
+            ParseErrorKind::TooShort { expected, min } => {
+                write!(f, "expected at least {} {}", min, expected)
+            }
+        }
+    }
+}
+
+/// A rejected piece of input, along with where it was found.
+///
+/// Lines and columns are 1-based, columns count characters rather than bytes.
+/// Both are 0 when the text couldn't be found in the input.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    pub kind: ParseErrorKind,
+    pub file: Option<PathBuf>,
+    pub line: usize,
+    pub column: usize,
+    /// The offending text, empty when something is missing
+    pub text: String,
+}
+
+impl ParseError {
+    /// Build an error for `text`, which must be a slice of `source` so that its
+    /// position can be worked out.
+    pub fn new(kind: ParseErrorKind, source: &str, text: &str) -> Self {
+        let (line, column) = locate(source, text).unwrap_or((0, 0));
+        Self {
+            kind,
+            file: None,
+            line,
+            column,
+            text: text.to_string(),
+        }
+    }
+
+    /// An error raised while parsing `inner`, moved to its position in `source`.
+    /// `inner` must be a slice of `source`.
+    pub fn rebase(self, source: &str, inner: &str) -> Self {
+        match locate(source, inner) {
+            Some((line, column)) => self.shift_to(line, column),
+            None => self,
+        }
+    }
+
+    /// An error raised while parsing a fragment of the input, moved to the
+    /// fragment's starting `line` and `column`
+    pub fn shift_to(mut self, line: usize, column: usize) -> Self {
+        if self.line == 0 || line == 0 {
+            return self;
+        }
+        if self.line == 1 {
+            self.column += column - 1;
+        }
+        self.line += line - 1;
+        self
+    }
+
+    pub fn with_file(mut self, file: impl AsRef<Path>) -> Self {
+        self.file = Some(file.as_ref().to_path_buf());
+        self
+    }
+
+    /// Render as a caret-style diagnostic pointing at the offending text in `source`
+    pub fn render(&self, source: &str) -> String {
+        let file = self
+            .file
+            .as_deref()
+            .map(|p| p.display().to_string())
+            .unwrap_or_else(|| "<input>".to_string());
+        if self.line == 0 {
+            return format!("error: {}\n --> {}", self.kind, file);
+        }
+        let source_line = source.lines().nth(self.line - 1).unwrap_or_default();
+        let gutter = " ".repeat(self.line.to_string().len());
+        let padding: String = source_line
+            .chars()
+            .take(self.column - 1)
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+        // Text spanning several lines is only underlined up to the end of its first
+        let rest_of_line = source_line.chars().count().saturating_sub(self.column - 1);
+        let carets = "^".repeat(self.text.chars().count().min(rest_of_line).max(1));
+
+        format!(
+            "error: {}\n{}--> {}:{}:{}\n{} |\n{} | {}\n{} | {}{}",
+            self.kind,
+            gutter,
+            file,
+            self.line,
+            self.column,
+            gutter,
+            self.line,
+            source_line,
+            gutter,
+            padding,
+            carets,
+        )
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file.display())?;
+        }
+        if self.line > 0 {
+            write!(f, "{}:{}:", self.line, self.column)?;
+        }
+        if self.file.is_some() || self.line > 0 {
+            write!(f, " ")?;
+        }
+        write!(f, "{}", self.kind)?;
+        if !self.text.is_empty() {
+            write!(f, ": \"{}\"", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.kind {
+            ParseErrorKind::InvalidNumber(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// Line and column of `text` within `source`, or `None` when `text` isn't a
+/// slice of `source`.
+fn locate(source: &str, text: &str) -> Option<(usize, usize)> {
+    let offset = (text.as_ptr() as usize).checked_sub(source.as_ptr() as usize)?;
+    if offset.checked_add(text.len())? > source.len() || !source.is_char_boundary(offset) {
+        return None;
+    }
+    let before = &source[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+    let column = before[line_start..].chars().count() + 1;
+    Some((line, column))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locate() {
+        let source = "L68\nR4x\n";
+        assert_eq!(locate(source, &source[0..1]), Some((1, 1)));
+        assert_eq!(locate(source, &source[5..7]), Some((2, 2)));
+        assert_eq!(locate(source, &source[8..]), Some((3, 1)));
+        assert_eq!(locate(source, "elsewhere"), None);
+        // Overlapping the end of the source
+        assert_eq!(locate(&source[..5], &source[4..7]), None);
+    }
+
+    #[test]
+    fn test_unlocated() {
+        let source = "é\n";
+        let err = ParseError::new(
+            ParseErrorKind::Missing {
+                expected: "a digit",
+            },
+            source,
+            "elsewhere",
+        )
+        .rebase(source, &source[3..])
+        .with_file("input.txt");
+        assert_eq!((err.line, err.column), (0, 0));
+        assert_eq!(err.render(source), "error: missing a digit\n --> input.txt");
+        assert_eq!(err.to_string(), "input.txt: missing a digit: \"elsewhere\"");
+    }
+
+    #[test]
+    fn test_rebase() {
+        let source = "L68\n  R4x\n";
+        let line = source.lines().nth(1).unwrap().trim();
+        let err = ParseError::new(
+            ParseErrorKind::Unexpected {
+                expected: "a digit",
+            },
+            line,
+            &line[2..3],
+        )
+        .rebase(source, line);
+        assert_eq!((err.line, err.column), (2, 5));
+    }
+
+    #[test]
+    fn test_render() {
+        let source = "L68\nR4x\n";
+        let err = ParseError::new(
+            ParseErrorKind::Unexpected {
+                expected: "a digit",
+            },
+            source,
+            &source[5..7],
+        )
+        .with_file("input.txt");
+        assert_eq!(
+            err.render(source),
+            "error: expected a digit\n --> input.txt:2:2\n  |\n2 | R4x\n  |  ^^"
+        );
+    }
+
+    #[test]
+    fn test_render_multiline_text() {
+        let source = "11-22\n95-115\n";
+        let err = ParseError::new(
+            ParseErrorKind::Unexpected {
+                expected: "a range",
+            },
+            source,
+            &source[3..10],
+        );
+        assert_eq!(
+            err.render(source),
+            "error: expected a range\n --> <input>:1:4\n  |\n1 | 11-22\n  |    ^^"
+        );
+    }
+}
@@ -0,0 +1,332 @@
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use crate::{ParseError, ParseErrorKind};
+
+/// Offsets to the four cells sharing an edge with a cell
+const NEIGHBORS4: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+/// Offsets to the eight cells surrounding a cell
+const NEIGHBORS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// A rectangular grid of cells, stored row by row in a single buffer.
+///
+/// Positions are `(row, column)` pairs counting from the top left.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// A grid of `width` columns from its cells, row by row
+    pub fn new(width: usize, cells: Vec<T>) -> Self {
+        let height = cells.len().checked_div(width).unwrap_or(0);
+        assert_eq!(
+            width * height,
+            cells.len(),
+            "Cells don't fill rows of {}",
+            width
+        );
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    /// Read a grid one character per cell, one row per line. Surrounding
+    /// whitespace and blank lines are ignored, every row must be the same
+    /// length and `cell` rejects a character by returning `None`.
+    pub fn parse_with(
+        input: &str,
+        expected: &'static str,
+        cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let rows = parse_rows(input, expected, cell)?;
+        let width = rows.first().map_or(0, |(_, row)| row.len());
+        let mut cells = Vec::with_capacity(width * rows.len());
+        for (line, row) in rows {
+            if row.len() != width {
+                return Err(ParseError::new(
+                    ParseErrorKind::Unexpected {
+                        expected: "a row as long as the first",
+                    },
+                    input,
+                    line,
+                ));
+            }
+            cells.extend(row);
+        }
+        Ok(Self::new(width, cells))
+    }
+
+    /// Read a grid like [`Grid::parse_with`], but extend rows shorter than the
+    /// longest with `fill` rather than rejecting them
+    pub fn parse_padded(
+        input: &str,
+        expected: &'static str,
+        fill: T,
+        cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError>
+    where
+        T: Clone,
+    {
+        let rows = parse_rows(input, expected, cell)?;
+        let width = rows.iter().map(|(_, row)| row.len()).max().unwrap_or(0);
+        let mut cells = Vec::with_capacity(width * rows.len());
+        for (_, mut row) in rows {
+            row.resize(width, fill.clone());
+            cells.extend(row);
+        }
+        Ok(Self::new(width, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        self.offset(row, col).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        self.offset(row, col).map(|i| &mut self.cells[i])
+    }
+
+    /// The cell at a position without checking the grid contains it
+    ///
+    /// # Safety
+    ///
+    /// `row` must be below the height and `col` below the width.
+    pub unsafe fn get_unchecked(&self, row: usize, col: usize) -> &T {
+        // SAFETY: the caller guarantees the position is within the grid, so
+        // its offset is within the buffer
+        unsafe { self.cells.get_unchecked(row * self.width + col) }
+    }
+
+    fn offset(&self, row: usize, col: usize) -> Option<usize> {
+        (row < self.height && col < self.width).then(|| row * self.width + col)
+    }
+
+    /// Every position in the grid, row by row
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    /// Every cell along with its position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// Positions sharing an edge with `(row, col)` that are within the grid
+    pub fn neighbors4(
+        &self,
+        row: usize,
+        col: usize,
+    ) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        self.offsets(row, col, &NEIGHBORS4)
+    }
+
+    /// Positions surrounding `(row, col)`, diagonals included, that are within
+    /// the grid
+    pub fn neighbors8(
+        &self,
+        row: usize,
+        col: usize,
+    ) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        self.offsets(row, col, &NEIGHBORS8)
+    }
+
+    fn offsets(
+        &self,
+        row: usize,
+        col: usize,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        let (width, height) = (self.width, self.height);
+        offsets.iter().filter_map(move |&(dr, dc)| {
+            let r = row.checked_add_signed(dr).filter(|&r| r < height)?;
+            let c = col.checked_add_signed(dc).filter(|&c| c < width)?;
+            Some((r, c))
+        })
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    /// Every row, top to bottom
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.width, "Column {} outside grid", col);
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    /// Every column, left to right
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|col| self.column(col))
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        self.get(row, col)
+            .unwrap_or_else(|| panic!("Position ({}, {}) outside grid", row, col))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        self.get_mut(row, col)
+            .unwrap_or_else(|| panic!("Position ({}, {}) outside grid", row, col))
+    }
+}
+
+/// A cell that can be read from a single character of a grid
+pub trait FromChar: Sized {
+    /// What the character should have been, for errors
+    const EXPECTED: &'static str;
+
+    fn from_char(c: char) -> Option<Self>;
+}
+
+impl FromChar for char {
+    const EXPECTED: &'static str = "a character";
+
+    fn from_char(c: char) -> Option<Self> {
+        Some(c)
+    }
+}
+
+impl FromChar for u8 {
+    const EXPECTED: &'static str = "a digit";
+
+    fn from_char(c: char) -> Option<Self> {
+        c.to_digit(10).map(|d| d as u8)
+    }
+}
+
+impl<T: FromChar> FromStr for Grid<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse_with(s, T::EXPECTED, T::from_char)
+    }
+}
+
+/// The cells of each non-blank line, along with the line they were read from
+fn parse_rows<'a, T>(
+    input: &'a str,
+    expected: &'static str,
+    mut cell: impl FnMut(char) -> Option<T>,
+) -> Result<Vec<(&'a str, Vec<T>)>, ParseError> {
+    input
+        .lines()
+        .map(|l| l.trim())
+        .filter(|l| !l.is_empty())
+        .map(|line| {
+            let row = line
+                .char_indices()
+                .map(|(idx, c)| {
+                    cell(c).ok_or_else(|| {
+                        ParseError::new(
+                            ParseErrorKind::Unexpected { expected },
+                            input,
+                            &line[idx..idx + c.len_utf8()],
+                        )
+                    })
+                })
+                .collect::<Result<_, _>>()?;
+            Ok((line, row))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let grid: Grid<u8> = "\n  123\n  456\n".parse().unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 2)], 6);
+        assert_eq!(grid.get(2, 0), None);
+        assert_eq!(grid.get(0, 3), None);
+        assert_eq!(unsafe { *grid.get_unchecked(1, 0) }, 4);
+
+        let err = "123\n4x6".parse::<Grid<u8>>().unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, "x"));
+
+        let err = "123\n45\n".parse::<Grid<u8>>().unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, "45"));
+
+        let grid: Grid<char> = "".parse().unwrap();
+        assert_eq!((grid.width(), grid.height()), (0, 0));
+    }
+
+    #[test]
+    fn test_parse_padded() {
+        let digit = |c: char| c.to_digit(10);
+        let grid = Grid::parse_padded("12\n3\n\n456", "a digit", 0, digit).unwrap();
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            vec![&[1, 2, 0], &[3, 0, 0], &[4, 5, 6]]
+        );
+
+        let err = Grid::parse_padded("12\n3x", "a digit", 0, digit).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, "x"));
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid: Grid<char> = "abc\ndef\nghi".parse().unwrap();
+        let cells = |positions: Vec<(usize, usize)>| -> String {
+            positions.into_iter().map(|p| grid[p]).collect()
+        };
+        assert_eq!(cells(grid.neighbors4(1, 1).collect()), "bdfh");
+        assert_eq!(cells(grid.neighbors8(1, 1).collect()), "abcdfghi");
+        assert_eq!(cells(grid.neighbors4(0, 0).collect()), "bd");
+        assert_eq!(cells(grid.neighbors8(2, 2).collect()), "efh");
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let mut grid: Grid<char> = "abc\ndef".parse().unwrap();
+        grid[(0, 1)] = 'x';
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            vec![&['a', 'x', 'c'], &['d', 'e', 'f']]
+        );
+        let columns: Vec<String> = grid.columns().map(|c| c.collect()).collect();
+        assert_eq!(columns, vec!["ad", "xe", "cf"]);
+        assert_eq!(
+            grid.iter()
+                .filter(|(_, c)| **c > 'c')
+                .map(|(p, _)| p)
+                .collect::<Vec<_>>(),
+            vec![(0, 1), (1, 0), (1, 1), (1, 2)]
+        );
+    }
+}
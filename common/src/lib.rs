@@ -0,0 +1,17 @@
+//! Plumbing shared by every day's puzzle
+use std::fs::read_to_string;
+use std::path::Path;
+
+pub mod cli;
+mod error;
+pub mod grid;
+pub mod output;
+mod solution;
+
+pub use error::{Error, ParseError, ParseErrorKind};
+pub use solution::{Answer, Part, Solution, Solved, solve, solve_parts};
+
+/// Read a puzzle input from disk
+pub fn read_input(path: impl AsRef<Path>) -> Result<String, Error> {
+    read_to_string(path.as_ref()).map_err(|e| Error::Io(Some(path.as_ref().to_path_buf()), e))
+}
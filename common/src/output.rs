@@ -0,0 +1,179 @@
+use clap::ValueEnum;
+
+use crate::Solved;
+
+/// How answers are written to stdout
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Human readable lines, including time spent parsing
+    #[default]
+    Text,
+    /// One JSON object per answer, one object per line
+    Json,
+    /// Tab separated answers, preceded by a header row
+    Tsv,
+}
+
+/// A single answer as emitted in the structured formats. `elapsed` is in seconds.
+struct Record<'a> {
+    day: u8,
+    part: u8,
+    answer: &'a str,
+    elapsed: f64,
+}
+
+impl Record<'_> {
+    fn to_json(&self) -> String {
+        format!(
+            "{{\"day\":{},\"part\":{},\"answer\":{},\"elapsed\":{}}}",
+            self.day,
+            self.part,
+            json_string(self.answer),
+            self.elapsed
+        )
+    }
+}
+
+/// Quote and escape a string for use as a JSON value
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Writes answers in a given format, keeping track of whether the TSV header
+/// has already been written so several days can share one table.
+pub struct Printer {
+    format: Format,
+    header_written: bool,
+}
+
+impl Printer {
+    pub fn new(format: Format) -> Self {
+        Self {
+            format,
+            header_written: false,
+        }
+    }
+
+    pub fn print(&mut self, solved: &Solved) {
+        for line in self.render(solved) {
+            println!("{}", line);
+        }
+    }
+
+    fn render(&mut self, solved: &Solved) -> Vec<String> {
+        let records = solved.answers.iter().map(|answer| Record {
+            day: solved.day,
+            part: answer.part,
+            answer: &answer.value,
+            elapsed: answer.elapsed.as_secs_f64(),
+        });
+
+        let mut lines = Vec::new();
+        match self.format {
+            Format::Text => {
+                lines.push(format!(
+                    "Day {} parsed in {:?}",
+                    solved.day, solved.parse_elapsed
+                ));
+                for answer in &solved.answers {
+                    lines.push(format!(
+                        "Day {} part {}: {} ({:?})",
+                        solved.day, answer.part, answer.value, answer.elapsed
+                    ));
+                }
+            }
+            Format::Json => {
+                for record in records {
+                    lines.push(record.to_json());
+                }
+            }
+            Format::Tsv => {
+                if !self.header_written {
+                    lines.push("day\tpart\tanswer\telapsed".to_string());
+                    self.header_written = true;
+                }
+                for record in records {
+                    lines.push(format!(
+                        "{}\t{}\t{}\t{}",
+                        record.day, record.part, record.answer, record.elapsed
+                    ));
+                }
+            }
+        }
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::Answer;
+
+    fn solved(day: u8) -> Solved {
+        Solved {
+            day,
+            parse_elapsed: Duration::from_millis(1),
+            answers: vec![
+                Answer {
+                    part: 1,
+                    value: "1158".to_string(),
+                    elapsed: Duration::from_millis(500),
+                },
+                Answer {
+                    part: 2,
+                    value: "6860".to_string(),
+                    elapsed: Duration::from_millis(250),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_render_json() {
+        assert_eq!(
+            Printer::new(Format::Json).render(&solved(1)),
+            vec![
+                r#"{"day":1,"part":1,"answer":"1158","elapsed":0.5}"#,
+                r#"{"day":1,"part":2,"answer":"6860","elapsed":0.25}"#,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_json_string() {
+        assert_eq!(json_string("1158"), r#""1158""#);
+        assert_eq!(json_string("a\"b\\c\n"), r#""a\"b\\c\n""#);
+    }
+
+    #[test]
+    fn test_render_tsv_header_once() {
+        let mut printer = Printer::new(Format::Tsv);
+        assert_eq!(
+            printer.render(&solved(1)),
+            vec![
+                "day\tpart\tanswer\telapsed",
+                "1\t1\t1158\t0.5",
+                "1\t2\t6860\t0.25"
+            ]
+        );
+        assert_eq!(
+            printer.render(&solved(2)),
+            vec!["2\t1\t1158\t0.5", "2\t2\t6860\t0.25"]
+        );
+    }
+}
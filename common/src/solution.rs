@@ -0,0 +1,147 @@
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+
+use clap::ValueEnum;
+
+use crate::ParseError;
+
+/// A day's puzzle: the input is parsed once into a typed model, which each part
+/// then answers from.
+pub trait Solution {
+    /// Day of the month the puzzle was released
+    const DAY: u8;
+
+    /// Typed model of the puzzle input
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Self::Answer1;
+    fn part2(input: &Self::Input) -> Self::Answer2;
+}
+
+/// Which of a day's parts to solve
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Part {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+    #[default]
+    Both,
+}
+
+impl Part {
+    pub fn numbers(self) -> &'static [u8] {
+        match self {
+            Part::One => &[1],
+            Part::Two => &[2],
+            Part::Both => &[1, 2],
+        }
+    }
+}
+
+/// A single part's rendered answer and how long it took to compute
+pub struct Answer {
+    pub part: u8,
+    pub value: String,
+    pub elapsed: Duration,
+}
+
+/// The answers for a day, along with the time spent parsing its input
+pub struct Solved {
+    pub day: u8,
+    pub parse_elapsed: Duration,
+    pub answers: Vec<Answer>,
+}
+
+/// Parse the input once and solve the requested parts, timing each step
+pub fn solve<S: Solution>(input: &str, part: Part) -> Result<Solved, ParseError> {
+    let start = Instant::now();
+    let input = S::parse(input)?;
+    let parse_elapsed = start.elapsed();
+
+    Ok(solve_parts(
+        S::DAY,
+        parse_elapsed,
+        part,
+        |part| match part {
+            1 => S::part1(&input).to_string(),
+            _ => S::part2(&input).to_string(),
+        },
+    ))
+}
+
+/// Answer the requested parts with `answer`, timing each one. Useful for
+/// binaries that can answer the parts differently from their [`Solution`].
+pub fn solve_parts(
+    day: u8,
+    parse_elapsed: Duration,
+    part: Part,
+    mut answer: impl FnMut(u8) -> String,
+) -> Solved {
+    let answers = part
+        .numbers()
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let value = answer(part);
+            Answer {
+                part,
+                value,
+                elapsed: start.elapsed(),
+            }
+        })
+        .collect();
+    Solved {
+        day,
+        parse_elapsed,
+        answers,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 0;
+
+        type Input = Vec<u32>;
+        type Answer1 = u32;
+        type Answer2 = u32;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            Ok(input.bytes().map(|b| (b - b'0') as u32).collect())
+        }
+
+        fn part1(input: &Self::Input) -> Self::Answer1 {
+            input.iter().sum()
+        }
+
+        fn part2(input: &Self::Input) -> Self::Answer2 {
+            input.iter().product()
+        }
+    }
+
+    #[test]
+    fn test_solve_selected_parts() {
+        let values = |part| {
+            solve::<Sum>("234", part)
+                .unwrap()
+                .answers
+                .into_iter()
+                .map(|a| (a.part, a.value))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(values(Part::One), vec![(1, "9".to_string())]);
+        assert_eq!(values(Part::Two), vec![(2, "24".to_string())]);
+        assert_eq!(
+            values(Part::Both),
+            vec![(1, "9".to_string()), (2, "24".to_string())]
+        );
+    }
+}
@@ -0,0 +1,302 @@
+// https://adventofcode.com/2025/day/1
+use std::path::Path;
+use std::str::FromStr;
+
+use common::{Error, ParseError, ParseErrorKind, Solution};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<Instruction>;
+    type Answer1 = u32;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_instructions(input)
+    }
+
+    /// Number of times the dial lands on 0
+    fn part1(instructions: &Self::Input) -> Self::Answer1 {
+        run_dial(instructions).landed_on_zero_count
+    }
+
+    /// Number of times the dial points at 0, including mid-rotation
+    fn part2(instructions: &Self::Input) -> Self::Answer2 {
+        run_dial(instructions).crossed_zero_count
+    }
+}
+
+pub fn read_instructions<P>(filename: P) -> Result<Vec<Instruction>, Error>
+where
+    P: AsRef<Path>,
+{
+    let input = common::read_input(&filename)?;
+    Ok(parse_instructions(&input).map_err(|e| e.with_file(filename))?)
+}
+
+pub fn parse_instructions(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    input
+        .lines()
+        .map(|l| l.trim())
+        .filter(|l| !l.is_empty())
+        .map(|l| l.parse().map_err(|e: ParseError| e.rebase(input, l)))
+        .collect()
+}
+
+/// Runs every instruction through a fresh dial, starting at 50
+pub fn run_dial(instructions: &[Instruction]) -> Dial {
+    let mut dial = Dial::new(50);
+    for instruction in instructions {
+        dial.rotate(instruction);
+    }
+    dial
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some(first) = s.chars().next() else {
+            return Err(ParseError::new(
+                ParseErrorKind::Missing {
+                    expected: "a direction",
+                },
+                s,
+                s,
+            ));
+        };
+        let (direction_str, distance_str) = s.split_at(first.len_utf8());
+        let direction = match direction_str {
+            "L" => Direction::Left,
+            "R" => Direction::Right,
+            _ => {
+                return Err(ParseError::new(
+                    ParseErrorKind::Unexpected {
+                        expected: "a direction of `L` or `R`",
+                    },
+                    s,
+                    direction_str,
+                ));
+            }
+        };
+        let distance: u16 = distance_str
+            .parse()
+            .map_err(|e| ParseError::new(ParseErrorKind::InvalidNumber(e), s, distance_str))?;
+        Ok(Instruction {
+            direction,
+            distance,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+#[derive(Debug)]
+pub struct Instruction {
+    direction: Direction,
+    distance: u16,
+}
+
+/// Represents the state of the dial
+pub struct Dial {
+    pub position: i16,
+    num_positions: i16,
+    pub landed_on_zero_count: u32,
+    pub crossed_zero_count: u64,
+}
+
+impl Dial {
+    pub fn new(position: i16) -> Self {
+        Dial {
+            position,
+            num_positions: 100,
+            landed_on_zero_count: 0,
+            crossed_zero_count: 0,
+        }
+    }
+
+    pub fn rotate(&mut self, i: &Instruction) {
+        let old_position = self.position;
+        // Widened so that distances past `i16::MAX` can't wrap
+        let position = self.position as i32;
+        let num_positions = self.num_positions as i32;
+        let delta = match i.direction {
+            Direction::Left => position - i.distance as i32,
+            Direction::Right => position + i.distance as i32,
+        };
+        let mut crossed = delta.div_euclid(num_positions).unsigned_abs() as u64;
+        self.position = delta.rem_euclid(num_positions) as i16;
+        log::debug!(
+            "The dial is rotated {:?}{} to point at {}{}",
+            i.direction,
+            i.distance,
+            self.position,
+            if crossed > 0 {
+                "; during this rotation, it points at 0 once."
+            } else {
+                "."
+            }
+        );
+        match i.direction {
+            Direction::Right => {}
+            Direction::Left => {
+                if self.position == 0 {
+                    crossed += 1; // Landed on 0, div_euclid missed it
+                }
+                if old_position == 0 && crossed > 0 {
+                    crossed -= 1; // Started on 0, didn't actually cross
+                }
+            }
+        }
+
+        self.crossed_zero_count += crossed;
+        if self.position == 0 {
+            self.landed_on_zero_count += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_long_rotation() {
+        for direction in [Direction::Right, Direction::Left] {
+            let mut dial = Dial::new(50);
+            dial.rotate(&Instruction {
+                direction,
+                distance: 40000,
+            });
+            assert_eq!(dial.position, 50);
+            assert_eq!(dial.crossed_zero_count, 400);
+        }
+    }
+
+    #[test]
+    fn test_crossing_zero_neg() {
+        let mut dial = Dial::new(2);
+        let instruction = Instruction {
+            direction: Direction::Left,
+            distance: 5,
+        };
+        dial.rotate(&instruction);
+        assert_eq!(dial.position, 97);
+    }
+
+    #[test]
+    fn test_crossing_zero_pos() {
+        let mut dial = Dial::new(97);
+        let instruction = Instruction {
+            direction: Direction::Right,
+            distance: 5,
+        };
+        dial.rotate(&instruction);
+        assert_eq!(dial.position, 2);
+    }
+
+    #[test]
+    fn test_solution_1() {
+        let mut dial = Dial::new(50);
+        let instructions = read_instructions("input.txt").expect("Failed to read instructions");
+        for instruction in instructions {
+            dial.rotate(&instruction);
+        }
+        assert_eq!(dial.landed_on_zero_count, 1158);
+    }
+
+    #[test]
+    fn test_parse_invalid_direction() {
+        let err = parse_instructions("L68\nX30\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, "X"));
+    }
+
+    #[test]
+    fn test_parse_invalid_distance() {
+        let err = parse_instructions("L68\n  R3o\n").unwrap_err();
+        assert!(matches!(err.kind, ParseErrorKind::InvalidNumber(_)));
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 4, "3o"));
+    }
+
+    #[test]
+    fn test_solution_2_example() {
+        /*f
+        The dial starts by pointing at 50.
+        The dial is rotated L68 to point at 82; during this rotation, it points at 0 once.
+        The dial is rotated L30 to point at 52.
+        The dial is rotated R48 to point at 0.
+        The dial is rotated L5 to point at 95.
+        The dial is rotated R60 to point at 55; during this rotation, it points at 0 once.
+        The dial is rotated L55 to point at 0.
+        The dial is rotated L1 to point at 99.
+        The dial is rotated L99 to point at 0.
+        The dial is rotated R14 to point at 14.
+        The dial is rotated L82 to point at 32; during this rotation, it points at 0 once.
+        */
+        let mut dial = Dial::new(50);
+        println!("  The dial starts by pointing at {}", dial.position);
+        let instructions = Vec::from([
+            "L68", "L30", "R48", "L5", "R60", "L55", "L1", "L99", "R14", "L82",
+        ]);
+        for i in instructions {
+            dial.rotate(&i.parse().unwrap());
+        }
+
+        assert_eq!(dial.position, 32);
+        assert_eq!(dial.crossed_zero_count, 6);
+    }
+
+    #[test]
+    fn test_crossovers_pos_1000() {
+        let mut dial = Dial::new(50);
+        dial.rotate(&"R1000".parse().unwrap());
+        assert_eq!(dial.position, 50);
+        assert_eq!(dial.crossed_zero_count, 10);
+    }
+
+    #[test]
+    fn test_crossovers_pos_55() {
+        let mut dial = Dial::new(50);
+        dial.rotate(&"R55".parse().unwrap());
+        assert_eq!(dial.position, 5);
+        assert_eq!(dial.crossed_zero_count, 1);
+    }
+
+    #[test]
+    fn test_crossovers_neg() {
+        let mut dial = Dial::new(50);
+        dial.rotate(&"L55".parse().unwrap());
+        assert_eq!(dial.position, 95);
+        assert_eq!(dial.crossed_zero_count, 1);
+    }
+
+    #[test]
+    fn test_crossovers_neg_lands_on_zero() {
+        let mut dial = Dial::new(50);
+        dial.rotate(&"L50".parse().unwrap());
+        assert_eq!(dial.position, 0);
+        assert_eq!(dial.crossed_zero_count, 1);
+    }
+
+    #[test]
+    fn test_crossovers_neg_starts_on_zero() {
+        let mut dial = Dial::new(0);
+        dial.rotate(&"L5".parse().unwrap());
+        assert_eq!(dial.position, 95);
+        assert_eq!(dial.crossed_zero_count, 0);
+    }
+
+    #[test]
+    fn test_crossovers_pos_lands_on_zero() {
+        let mut dial = Dial::new(50);
+        dial.rotate(&"R50".parse().unwrap());
+        assert_eq!(dial.position, 0);
+        assert_eq!(dial.crossed_zero_count, 1);
+    }
+}
@@ -0,0 +1,940 @@
+// https://adventofcode.com/2025/day/2
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt::{self, Display};
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::ops::RangeInclusive;
+use std::path::Path;
+use std::str::FromStr;
+
+use common::{Error, ParseError, ParseErrorKind, Solution};
+
+mod repeats;
+
+pub use repeats::{invalid_id_count, invalid_id_sum, invalid_ids};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<(u64, u64)>;
+    type Answer1 = u128;
+    type Answer2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_ranges(input)
+    }
+
+    /// Sum of IDs made of a sequence repeated twice
+    fn part1(ranges: &Self::Input) -> Self::Answer1 {
+        total_invalid_ids(ranges, RepeatMode::Twice)
+    }
+
+    /// Sum of IDs made of a sequence repeated at least twice
+    fn part2(ranges: &Self::Input) -> Self::Answer2 {
+        total_invalid_ids(ranges, RepeatMode::Multi)
+    }
+}
+
+pub fn get_ranges<P>(path: P) -> Result<Vec<(u64, u64)>, Error>
+where
+    P: AsRef<Path>,
+{
+    let file = File::open(&path).map_err(|e| Error::Io(Some(path.as_ref().to_path_buf()), e))?;
+    read_ranges(BufReader::new(file)).map_err(|e| match e {
+        Error::Parse(e) => Error::Parse(e.with_file(path)),
+        e => e,
+    })
+}
+
+pub fn read_ranges(f: impl BufRead) -> Result<Vec<(u64, u64)>, Error> {
+    RangeReader::new(f).collect()
+}
+
+pub fn parse_ranges(input: &str) -> Result<Vec<(u64, u64)>, ParseError> {
+    RangeReader::new(input.as_bytes())
+        .map(|range| {
+            range.map_err(|e| match e {
+                Error::Parse(e) => e,
+                Error::Io(..) => unreachable!("Reading from memory can't fail"),
+            })
+        })
+        .collect()
+}
+
+/// Streams the comma separated `start-end` ranges out of a reader, one token at
+/// a time. Whitespace (including newlines) around and within tokens is ignored,
+/// as are empty tokens such as a trailing comma, and whitespace after a
+/// complete range separates it from the next just as a comma does. Reversed
+/// ranges and ranges overlapping an earlier one are rejected.
+pub struct RangeReader<R> {
+    reader: R,
+    buf: Vec<u8>,
+    // Where the next token starts, so errors can point at the offending text
+    line: usize,
+    column: usize,
+    // Ranges read so far, keyed by start, to spot overlaps
+    seen: BTreeMap<u64, u64>,
+    // Ranges from the last token not yet returned, ending at its first error
+    pending: VecDeque<Result<(u64, u64), Error>>,
+}
+
+impl<R: BufRead> RangeReader<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            buf: Vec::new(),
+            line: 1,
+            column: 1,
+            seen: BTreeMap::new(),
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// Read the next token into `pending`, returning `None` once the input is
+    /// exhausted
+    fn next_token(&mut self) -> Option<()> {
+        self.buf.clear();
+        match self.reader.read_until(b',', &mut self.buf) {
+            Ok(0) => return None,
+            Ok(_) => {}
+            Err(e) => {
+                self.pending.push_back(Err(e.into()));
+                return Some(());
+            }
+        }
+        let (line, column) = (self.line, self.column);
+        let token = String::from_utf8_lossy(&self.buf);
+        for c in token.chars() {
+            if c == '\n' {
+                (self.line, self.column) = (self.line + 1, 1);
+            } else {
+                self.column += 1;
+            }
+        }
+
+        let Ok(token) = std::str::from_utf8(&self.buf) else {
+            let err = ParseError::new(
+                ParseErrorKind::Unexpected {
+                    expected: "UTF-8 text",
+                },
+                &token,
+                &token,
+            );
+            self.pending
+                .push_back(Err(err.shift_to(line, column).into()));
+            return Some(());
+        };
+        for text in range_texts(token) {
+            let range = parse_range(token, text).and_then(|(start, end)| {
+                // Ranges already seen never overlap, so only the last one
+                // starting before this one ends can overlap it
+                let overlaps = self
+                    .seen
+                    .range(..=end)
+                    .next_back()
+                    .is_some_and(|(_, &seen_end)| seen_end >= start);
+                if overlaps {
+                    return Err(ParseError::new(
+                        ParseErrorKind::Unexpected {
+                            expected: "a range not overlapping an earlier one",
+                        },
+                        token,
+                        text,
+                    ));
+                }
+                self.seen.insert(start, end);
+                Ok((start, end))
+            });
+            let failed = range.is_err();
+            self.pending
+                .push_back(range.map_err(|e| e.shift_to(line, column).into()));
+            if failed {
+                break;
+            }
+        }
+        Some(())
+    }
+}
+
+impl<R: BufRead> Iterator for RangeReader<R> {
+    type Item = Result<(u64, u64), Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(range) = self.pending.pop_front() {
+                return Some(range);
+            }
+            self.next_token()?;
+        }
+    }
+}
+
+/// The text of each `start-end` range in a token, without surrounding
+/// whitespace or commas. Whitespace only ends a range once both of its ends
+/// have started, so `11-22\n95-115` holds two ranges but `95-\n115` holds one.
+fn range_texts(token: &str) -> Vec<&str> {
+    let token = token.trim().trim_matches(',').trim();
+    let mut texts = Vec::new();
+    let mut start = None;
+    let (mut seen_dash, mut seen_end) = (false, false);
+    for (i, c) in token.char_indices() {
+        if c.is_whitespace() {
+            if let (Some(s), true) = (start, seen_end) {
+                texts.push(&token[s..i]);
+                start = None;
+                (seen_dash, seen_end) = (false, false);
+            }
+            continue;
+        }
+        start.get_or_insert(i);
+        if seen_dash {
+            seen_end = true;
+        } else if c == '-' {
+            seen_dash = true;
+        }
+    }
+    if let Some(s) = start {
+        texts.push(&token[s..]);
+    }
+    texts
+}
+
+/// Parse the `start-end` range `text`, a slice of `token` as found by
+/// [`range_texts`]
+fn parse_range(token: &str, text: &str) -> Result<(u64, u64), ParseError> {
+    let (start_str, end_str) = text.split_once('-').ok_or_else(|| {
+        ParseError::new(
+            ParseErrorKind::Missing {
+                expected: "`-` between start and end of range",
+            },
+            token,
+            text,
+        )
+    })?;
+    let parse = |s: &str| {
+        let s = s.trim();
+        s.parse::<u64>()
+            .map_err(|e| ParseError::new(ParseErrorKind::InvalidNumber(e), token, s))
+    };
+    let (start, end) = (parse(start_str)?, parse(end_str)?);
+    if start > end {
+        return Err(ParseError::new(
+            ParseErrorKind::Unexpected {
+                expected: "a range ending at or after its start",
+            },
+            token,
+            text,
+        ));
+    }
+    Ok((start, end))
+}
+
+pub fn get_invalid_ids(id_ranges: Vec<(u64, u64)>, policy: impl Into<RepeatPolicy>) -> Vec<u64> {
+    let policy = policy.into();
+    map_pieces(
+        pieces(&id_ranges, &policy, IDS_PER_PIECE),
+        |_, (start, end)| {
+            log::debug!("Reviewing range {}-{}", start, end);
+            invalid_ids(start, end, &policy)
+                .inspect(|num| log::trace!(" - match: {}", num))
+                .collect::<Vec<_>>()
+        },
+    )
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// An invalid ID along with the pattern that makes it invalid
+#[derive(Clone, Debug, PartialEq)]
+pub struct InvalidId {
+    pub id: u64,
+    /// Shortest pattern the ID is made of, written in the policy's radix
+    pub unit: String,
+    /// How many times `unit` is repeated to make the ID
+    pub repeats: u32,
+    /// The range the ID was found in
+    pub range: (u64, u64),
+}
+
+/// Every invalid ID across all ranges, in the order the ranges are given, along
+/// with the shortest pattern each one repeats. The IDs are found a batch of
+/// pieces at a time as the iterator is consumed, so huge ranges don't need to
+/// fit in memory.
+pub fn find_invalid_ids(
+    id_ranges: &[(u64, u64)],
+    policy: impl Into<RepeatPolicy>,
+) -> impl Iterator<Item = InvalidId> {
+    let policy = policy.into();
+    let batches: Vec<Vec<_>> = pieces(id_ranges, &policy, IDS_PER_PIECE)
+        .chunks(PIECES_PER_BATCH)
+        .map(<[_]>::to_vec)
+        .collect();
+    batches.into_iter().flat_map(move |batch| {
+        map_pieces(batch, |range, (start, end)| {
+            invalid_ids(start, end, &policy)
+                .map(|id| {
+                    let digits = to_radix_string(id, policy.radix);
+                    let unit = shortest_unit(&digits);
+                    InvalidId {
+                        id,
+                        repeats: (digits.len() / unit.len()) as u32,
+                        unit: unit.to_string(),
+                        range,
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
+        .into_iter()
+        .flatten()
+    })
+}
+
+/// Shortest prefix that repeats to make up the whole of `val`, which is `val`
+/// itself when it has no repeats
+fn shortest_unit(val: &str) -> &str {
+    let len = val.len();
+    let period = (1..=len)
+        .filter(|d| len.is_multiple_of(*d))
+        .find(|&d| all_elements_are_equal(&chunk_string(val, d)))
+        .unwrap_or(len);
+    &val[..period]
+}
+
+/// Sum of every invalid ID across all ranges
+pub fn total_invalid_ids(id_ranges: &[(u64, u64)], policy: impl Into<RepeatPolicy>) -> u128 {
+    let policy = policy.into();
+    // Summing a piece takes as long whatever its size, so there's no need to
+    // split it further
+    map_pieces(pieces(id_ranges, &policy, u128::MAX), |_, (start, end)| {
+        invalid_id_sum(start, end, &policy)
+    })
+    .into_iter()
+    .sum()
+}
+
+/// Most invalid IDs a piece of a range should hold when listing them, so that a
+/// single large range is still shared across threads
+const IDS_PER_PIECE: u128 = 1 << 12;
+
+/// Pieces whose invalid IDs are found together by [`find_invalid_ids`]
+const PIECES_PER_BATCH: usize = 64;
+
+/// Split every range into pieces, each paired with the range it came from and
+/// in range order. Pieces never straddle a change in the number of digits, and
+/// each holds about `max_ids` invalid IDs at most.
+fn pieces(
+    id_ranges: &[(u64, u64)],
+    policy: &RepeatPolicy,
+    max_ids: u128,
+) -> Vec<((u64, u64), (u64, u64))> {
+    id_ranges
+        .iter()
+        .flat_map(|&range| {
+            split_by_digits(range, policy.radix)
+                .flat_map(|(start, end)| {
+                    // Invalid IDs of the same length are spread evenly, so equal
+                    // slices of the piece hold about as many of them
+                    let count = invalid_id_count(start, end, policy);
+                    split_evenly((start, end), count.div_ceil(max_ids).max(1))
+                })
+                .map(move |piece| (range, piece))
+        })
+        .collect()
+}
+
+/// Apply `f` to every piece along with the range it came from. The results are
+/// in the same order as the pieces whether or not the `parallel` feature is
+/// enabled.
+fn map_pieces<T: Send>(
+    pieces: Vec<((u64, u64), (u64, u64))>,
+    f: impl Fn((u64, u64), (u64, u64)) -> T + Sync,
+) -> Vec<T> {
+    #[cfg(feature = "parallel")]
+    {
+        use rayon::prelude::*;
+        pieces
+            .into_par_iter()
+            .map(|(range, piece)| f(range, piece))
+            .collect()
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        pieces
+            .into_iter()
+            .map(|(range, piece)| f(range, piece))
+            .collect()
+    }
+}
+
+/// Split `start..=end` into pieces whose IDs all have the same number of digits
+fn split_by_digits((start, end): (u64, u64), radix: u32) -> impl Iterator<Item = (u64, u64)> {
+    let mut next = (start <= end).then_some(start);
+    std::iter::from_fn(move || {
+        let piece_start = next?;
+        let len = repeats::digits(piece_start, radix);
+        let piece_end = (repeats::pow(radix, len) - 1).min(end as u128) as u64;
+        next = piece_end.checked_add(1).filter(|&n| n <= end);
+        Some((piece_start, piece_end))
+    })
+}
+
+/// Split `start..=end` into `parts` ranges of about the same size, fewer when
+/// there aren't enough IDs to go round
+fn split_evenly((start, end): (u64, u64), parts: u128) -> impl Iterator<Item = (u64, u64)> {
+    let width = (end as u128 - start as u128 + 1).div_ceil(parts);
+    (0..parts)
+        .map(move |i| start as u128 + i * width)
+        .take_while(move |&piece_start| piece_start <= end as u128)
+        .map(move |piece_start| {
+            let piece_end = (piece_start + width - 1).min(end as u128);
+            (piece_start as u64, piece_end as u64)
+        })
+}
+
+/// Check a single ID by comparing its digits, slower than enumerating the
+/// invalid IDs of a range but handy for spot checks
+pub fn is_invalid_id(num: u64, policy: &RepeatPolicy) -> bool {
+    let num_str = to_radix_string(num, policy.radix);
+    match policy.mode {
+        RepeatMode::Multi => has_patterns(num_str),
+        _ => policy
+            .mode
+            .periods(num_str.len() as u32)
+            .into_iter()
+            .any(|period| all_elements_are_equal(&chunk_string(&num_str, period as usize))),
+    }
+}
+
+fn to_radix_string(mut num: u64, radix: u32) -> String {
+    let mut digits = Vec::new();
+    loop {
+        let digit = (num % radix as u64) as u32;
+        digits.push(char::from_digit(digit, radix).expect("Digit is always below radix"));
+        num /= radix as u64;
+        if num == 0 {
+            break;
+        }
+    }
+    digits.iter().rev().collect()
+}
+
+fn has_patterns(val: String) -> bool {
+    if val.len() < 2 {
+        return false;
+    }
+    let range_end = match val.len() % 2 {
+        0 => val.len() / 2,
+        _ => val.len() / 2 + 1,
+    };
+    for i in 1..range_end + 1 {
+        let chunks = chunk_string(&val, i);
+        if all_elements_are_equal(&chunks) {
+            return true;
+        }
+    }
+    false
+}
+
+fn all_elements_are_equal(vec: &Vec<String>) -> bool {
+    let first_val = &vec[0];
+    for val in vec {
+        if *val != *first_val {
+            return false;
+        }
+    }
+    true
+}
+
+fn chunk_string(string: &str, n: usize) -> Vec<String> {
+    let mut buf = string.chars().peekable();
+    let mut out = Vec::<String>::new();
+    while buf.peek().is_some() {
+        out.push(buf.by_ref().take(n).collect());
+    }
+    out
+}
+
+#[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pt1_example_invalid_ids() {
+        let mode = RepeatMode::Twice;
+        assert_eq!(get_invalid_ids(vec![(11, 22)], mode.clone()), vec![11, 22]);
+        assert_eq!(get_invalid_ids(vec![(95, 115)], mode.clone()), vec![99]);
+        assert_eq!(get_invalid_ids(vec![(998, 1012)], mode.clone()), vec![1010]);
+        assert_eq!(
+            get_invalid_ids(vec![(1188511880, 1188511890)], mode.clone()),
+            vec![1188511885]
+        );
+        assert_eq!(
+            get_invalid_ids(vec![(222220, 222224)], mode.clone()),
+            vec![222222]
+        );
+        assert_eq!(
+            get_invalid_ids(vec![(1698522, 1698528)], mode.clone()),
+            vec![]
+        );
+        assert_eq!(
+            get_invalid_ids(vec![(446443, 446449)], mode.clone()),
+            vec![446446]
+        );
+        assert_eq!(
+            get_invalid_ids(vec![(38593856, 38593862)], mode.clone()),
+            vec![38593859]
+        );
+    }
+
+    #[test]
+    fn test_pt1_example_sum() {
+        assert_eq!(
+            get_invalid_ids(
+                vec![
+                    (11, 22),
+                    (95, 115),
+                    (998, 1012),
+                    (1188511880, 1188511890),
+                    (222220, 222224),
+                    (1698522, 1698528),
+                    (446443, 446449),
+                    (38593856, 38593862),
+                ],
+                RepeatMode::Twice
+            )
+            .iter()
+            .sum::<u64>(),
+            1227775554
+        )
+    }
+
+    #[test]
+    fn test_pt2_example_invalid_ids() {
+        let mode = RepeatMode::Multi;
+        assert_eq!(get_invalid_ids(vec![(11, 22)], mode.clone()), vec![11, 22]);
+        assert_eq!(
+            get_invalid_ids(vec![(95, 115)], mode.clone()),
+            vec![99, 111]
+        );
+        assert_eq!(
+            get_invalid_ids(vec![(998, 1012)], mode.clone()),
+            vec![999, 1010]
+        );
+        assert_eq!(
+            get_invalid_ids(vec![(1188511880, 1188511890)], mode.clone()),
+            vec![1188511885]
+        );
+        assert_eq!(
+            get_invalid_ids(vec![(222220, 222224)], mode.clone()),
+            vec![222222]
+        );
+        assert_eq!(
+            get_invalid_ids(vec![(1698522, 1698528)], mode.clone()),
+            vec![]
+        );
+        assert_eq!(
+            get_invalid_ids(vec![(446443, 446449)], mode.clone()),
+            vec![446446]
+        );
+        assert_eq!(
+            get_invalid_ids(vec![(38593856, 38593862)], mode.clone()),
+            vec![38593859]
+        );
+        assert_eq!(
+            get_invalid_ids(vec![(565653, 565659)], mode.clone()),
+            vec![565656]
+        );
+        assert_eq!(
+            get_invalid_ids(vec![(824824821, 824824827)], mode.clone()),
+            vec![824824824]
+        );
+        assert_eq!(
+            get_invalid_ids(vec![(2121212118, 2121212124)], mode.clone()),
+            vec![2121212121]
+        );
+    }
+
+    #[test]
+    fn test_pt2_example_sum() {
+        assert_eq!(
+            get_invalid_ids(
+                vec![
+                    (11, 22),
+                    (95, 115),
+                    (998, 1012),
+                    (1188511880, 1188511890),
+                    (222220, 222224),
+                    (1698522, 1698528),
+                    (446443, 446449),
+                    (38593856, 38593862),
+                    (565653, 565659),
+                    (824824821, 824824827),
+                    (2121212118, 2121212124)
+                ],
+                RepeatMode::Multi
+            )
+            .iter()
+            .sum::<u64>(),
+            4174379265
+        )
+    }
+
+    #[test]
+    fn test_parse_ranges() {
+        assert_eq!(
+            parse_ranges("11-22,95-115,\n").unwrap(),
+            vec![(11, 22), (95, 115)]
+        );
+        let err = parse_ranges("11-22,\n95115").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, "95115"));
+        let err = parse_ranges("11-22,95-1x5").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 10, "1x5"));
+    }
+
+    #[test]
+    fn test_parse_ranges_whitespace() {
+        assert_eq!(
+            parse_ranges(" 11 - 22 ,\n\n 95-\n115 ,, ,").unwrap(),
+            vec![(11, 22), (95, 115)]
+        );
+        assert_eq!(parse_ranges("").unwrap(), vec![]);
+        assert_eq!(parse_ranges(",\n").unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_parse_ranges_newlines() {
+        assert_eq!(
+            parse_ranges("11-22\n95-115\n").unwrap(),
+            vec![(11, 22), (95, 115)]
+        );
+        assert_eq!(
+            parse_ranges("11-22 95 -\n115\t998-1012,").unwrap(),
+            vec![(11, 22), (95, 115), (998, 1012)]
+        );
+        let err = parse_ranges("11-22\n95-1x5\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 4, "1x5"));
+        let err = parse_ranges("11-22\n20-30").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, "20-30"));
+    }
+
+    #[test]
+    fn test_parse_ranges_invalid() {
+        let err = parse_ranges("11-22,\n  30-25").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, "30-25"));
+        let err = parse_ranges("11-22,-5").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 7, ""));
+        let err = parse_ranges("11-22,33-").unwrap_err();
+        assert!(matches!(err.kind, ParseErrorKind::InvalidNumber(_)));
+    }
+
+    #[test]
+    fn test_parse_ranges_overlap() {
+        assert!(parse_ranges("11-22,23-30,1-10").is_ok());
+        let err = parse_ranges("11-22,30-40,\n5-11").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, "5-11"));
+        let err = parse_ranges("11-22,30-40,12-13").unwrap_err();
+        assert_eq!(err.text, "12-13");
+        let err = parse_ranges("11-22,30-40,1-50").unwrap_err();
+        assert_eq!(err.text, "1-50");
+    }
+
+    #[test]
+    fn test_read_ranges_matches_parse_ranges() {
+        let input = "11-22,\n 95-115,998-1o12";
+        let Err(Error::Parse(err)) = read_ranges(input.as_bytes()) else {
+            panic!("Expected a parse error");
+        };
+        assert_eq!(err, parse_ranges(input).unwrap_err());
+    }
+
+    #[test]
+    fn test_chunk_string() {
+        assert_eq!(
+            chunk_string("AAABBBCCC", 1),
+            vec!["A", "A", "A", "B", "B", "B", "C", "C", "C"]
+        );
+        assert_eq!(
+            chunk_string("AAABBBCCC", 2),
+            vec!["AA", "AB", "BB", "CC", "C"]
+        );
+        assert_eq!(chunk_string("AAABBBCCC", 3), vec!["AAA", "BBB", "CCC"]);
+        assert_eq!(chunk_string("AAABBBCCC", 4), vec!["AAAB", "BBCC", "C"]);
+    }
+
+    #[test]
+    fn test_all_elements_are_equal() {
+        assert_eq!(
+            all_elements_are_equal(&vec!["A".to_string(), "A".to_string(), "A".to_string()]),
+            true
+        );
+        assert_eq!(
+            all_elements_are_equal(&vec!["AA".to_string(), "A".to_string(), "A".to_string()]),
+            false
+        );
+        assert_eq!(
+            all_elements_are_equal(&vec!["A".to_string(), "A".to_string(), "B".to_string()]),
+            false
+        );
+    }
+
+    #[test]
+    fn test_repeat_mode_from_str() {
+        assert_eq!("twice".parse(), Ok(RepeatMode::Twice));
+        assert_eq!("exactly:3".parse(), Ok(RepeatMode::Exactly(3)));
+        assert_eq!("at-least:2".parse(), Ok(RepeatMode::AtLeast(2)));
+        assert_eq!(
+            "pattern-len:2..=4".parse(),
+            Ok(RepeatMode::PatternLen(2..=4))
+        );
+        assert_eq!(
+            "pattern-len:2..4".parse(),
+            Ok(RepeatMode::PatternLen(2..=3))
+        );
+        assert_eq!("pattern-len:3".parse(), Ok(RepeatMode::PatternLen(3..=3)));
+        assert!("exactly".parse::<RepeatMode>().is_err());
+        assert!("sometimes".parse::<RepeatMode>().is_err());
+        assert!("exactly:0".parse::<RepeatMode>().is_err());
+        assert!("at-least:0".parse::<RepeatMode>().is_err());
+        assert!("pattern-len:3..=1".parse::<RepeatMode>().is_err());
+        assert!("pattern-len:0..0".parse::<RepeatMode>().is_err());
+        assert!("pattern-len:2..2".parse::<RepeatMode>().is_err());
+        assert!("pattern-len:0..=2".parse::<RepeatMode>().is_err());
+        assert!("pattern-len:0".parse::<RepeatMode>().is_err());
+        let mode = RepeatMode::PatternLen(1..=5);
+        assert_eq!(mode.to_string().parse(), Ok(mode));
+    }
+
+    #[test]
+    fn test_repeat_policies() {
+        // 111 is "1" three times, 1212 is "12" twice, 121212 is "12" three times
+        let ranges = vec![(100, 130), (1200, 1300), (121212, 121212)];
+        assert_eq!(
+            get_invalid_ids(ranges.clone(), RepeatMode::Exactly(3)),
+            vec![111, 121212]
+        );
+        assert_eq!(
+            get_invalid_ids(ranges.clone(), RepeatMode::AtLeast(3)),
+            vec![111, 121212]
+        );
+        assert_eq!(
+            get_invalid_ids(ranges.clone(), RepeatMode::PatternLen(2..=2)),
+            vec![1212, 121212]
+        );
+        // 0b1010 and 0b1111 repeat a two-digit pattern twice in binary
+        assert_eq!(
+            get_invalid_ids(vec![(8, 15)], RepeatPolicy::new(RepeatMode::Twice, 2)),
+            vec![10, 15]
+        );
+    }
+
+    #[test]
+    fn test_find_invalid_ids() {
+        let found =
+            find_invalid_ids(&[(95, 115), (222220, 222224)], RepeatMode::Multi).collect::<Vec<_>>();
+        let summary: Vec<_> = found
+            .iter()
+            .map(|m| (m.id, m.unit.as_str(), m.repeats, m.range))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (99, "9", 2, (95, 115)),
+                (111, "1", 3, (95, 115)),
+                (222222, "2", 6, (222220, 222224)),
+            ]
+        );
+
+        // The unit is the shortest pattern, whichever repeat the mode asked for
+        let found =
+            find_invalid_ids(&[(1212, 1212), (2222, 2222)], RepeatMode::Twice).collect::<Vec<_>>();
+        assert_eq!((found[0].unit.as_str(), found[0].repeats), ("12", 2));
+        assert_eq!((found[1].unit.as_str(), found[1].repeats), ("2", 4));
+
+        let found = find_invalid_ids(&[(10, 10)], RepeatPolicy::new(RepeatMode::Twice, 2))
+            .collect::<Vec<_>>();
+        assert_eq!((found[0].unit.as_str(), found[0].repeats), ("10", 2));
+    }
+
+    #[test]
+    fn test_split_by_digits() {
+        assert_eq!(
+            split_by_digits((5, 1234), 10).collect::<Vec<_>>(),
+            vec![(5, 9), (10, 99), (100, 999), (1000, 1234)]
+        );
+        assert_eq!(
+            split_by_digits((2, 5), 2).collect::<Vec<_>>(),
+            vec![(2, 3), (4, 5)]
+        );
+        assert_eq!(split_by_digits((10, 1), 10).count(), 0);
+        assert_eq!(
+            split_by_digits((u64::MAX - 1, u64::MAX), 10).collect::<Vec<_>>(),
+            vec![(u64::MAX - 1, u64::MAX)]
+        );
+    }
+
+    #[test]
+    fn test_split_evenly() {
+        assert_eq!(
+            split_evenly((10, 19), 3).collect::<Vec<_>>(),
+            vec![(10, 13), (14, 17), (18, 19)]
+        );
+        assert_eq!(
+            split_evenly((5, 6), 4).collect::<Vec<_>>(),
+            vec![(5, 5), (6, 6)]
+        );
+        assert_eq!(
+            split_evenly((0, u64::MAX), 2).collect::<Vec<_>>(),
+            vec![(0, u64::MAX / 2), (u64::MAX / 2 + 1, u64::MAX)]
+        );
+    }
+
+    #[test]
+    fn test_pieces_bounded() {
+        let policy = RepeatMode::Multi.into();
+        let ranges = [(1_000_000_000_000_000_000, u64::MAX), (11, 22)];
+        let pieces = pieces(&ranges, &policy, IDS_PER_PIECE);
+        assert!(pieces.len() > 1);
+        // Pieces follow on from each other, in range order
+        assert_eq!(pieces[0], (ranges[0], (ranges[0].0, pieces[0].1.1)));
+        for pair in pieces.windows(2).filter(|p| p[1].0 == ranges[0]) {
+            assert_eq!(pair[0].1.1 + 1, pair[1].1.0);
+        }
+        assert_eq!(pieces[pieces.len() - 2].1.1, u64::MAX);
+        assert_eq!(pieces[pieces.len() - 1], (ranges[1], ranges[1]));
+        for &(_, (start, end)) in &pieces {
+            assert!(invalid_id_count(start, end, &policy) <= 2 * IDS_PER_PIECE);
+        }
+        assert_eq!(
+            find_invalid_ids(&ranges, RepeatMode::Multi)
+                .take(2)
+                .map(|m| m.id)
+                .collect::<Vec<_>>(),
+            vec![1_111_111_111_111_111_111, 2_222_222_222_222_222_222]
+        );
+    }
+
+    #[test]
+    fn test_has_patterns() {
+        assert_eq!(has_patterns("121121".to_string()), true);
+        assert_eq!(has_patterns("1".to_string()), false);
+    }
+}
+
+/// Which repetitions make an ID invalid. An ID "repeats a pattern `k` times"
+/// when it's made of exactly `k` copies of some pattern, so 1111 repeats `11`
+/// twice as well as `1` four times.
+#[derive(Clone, Debug, PartialEq)]
+pub enum RepeatMode {
+    // Pt. 1
+    Twice,
+    // Pt. 2
+    Multi,
+    /// A pattern repeated exactly this many times
+    Exactly(u32),
+    /// A pattern repeated at least this many times
+    AtLeast(u32),
+    /// A pattern of one of these lengths repeated at least twice
+    PatternLen(RangeInclusive<u32>),
+}
+
+impl RepeatMode {
+    /// Pattern lengths that make an ID of `len` digits invalid
+    pub(crate) fn periods(&self, len: u32) -> Vec<u32> {
+        let divisors = (1..=len).filter(|d| len.is_multiple_of(*d));
+        match self {
+            RepeatMode::Twice => divisors.filter(|d| len / d == 2).collect(),
+            RepeatMode::Multi => divisors.filter(|&d| d < len).collect(),
+            RepeatMode::Exactly(k) => divisors.filter(|d| len / d == *k).collect(),
+            RepeatMode::AtLeast(k) => divisors.filter(|d| len / d >= *k).collect(),
+            RepeatMode::PatternLen(lens) => {
+                divisors.filter(|&d| d < len && lens.contains(&d)).collect()
+            }
+        }
+    }
+}
+
+impl Display for RepeatMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RepeatMode::Twice => write!(f, "twice"),
+            RepeatMode::Multi => write!(f, "multi"),
+            RepeatMode::Exactly(k) => write!(f, "exactly:{}", k),
+            RepeatMode::AtLeast(k) => write!(f, "at-least:{}", k),
+            RepeatMode::PatternLen(lens) => {
+                write!(f, "pattern-len:{}..={}", lens.start(), lens.end())
+            }
+        }
+    }
+}
+
+/// Parses `twice`, `multi`, `exactly:K`, `at-least:K` and `pattern-len:A..=B`
+/// (or `A..B`, or a single length)
+impl FromStr for RepeatMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let number = |v: &str| {
+            v.trim()
+                .parse::<u32>()
+                .map_err(|e| format!("invalid number {:?}: {}", v, e))
+        };
+        let count = |v: &str| match number(v)? {
+            0 => Err(format!("repeat count must be at least 1, found {:?}", v)),
+            k => Ok(k),
+        };
+        let (name, arg) = s.split_once(':').unwrap_or((s, ""));
+        match (name, arg) {
+            ("twice", "") => Ok(RepeatMode::Twice),
+            ("multi", "") => Ok(RepeatMode::Multi),
+            ("exactly", k) => Ok(RepeatMode::Exactly(count(k)?)),
+            ("at-least", k) => Ok(RepeatMode::AtLeast(count(k)?)),
+            ("pattern-len", lens) => {
+                let range = if let Some((a, b)) = lens.split_once("..=") {
+                    number(a)?..=number(b)?
+                } else if let Some((a, b)) = lens.split_once("..") {
+                    match number(b)?.checked_sub(1) {
+                        Some(end) => number(a)?..=end,
+                        None => return Err(format!("pattern lengths {:?} are empty", lens)),
+                    }
+                } else {
+                    number(lens)?..=number(lens)?
+                };
+                if range.is_empty() || *range.start() == 0 {
+                    return Err(format!(
+                        "pattern lengths {:?} must be a non-empty range starting at 1 or more",
+                        lens
+                    ));
+                }
+                Ok(RepeatMode::PatternLen(range))
+            }
+            _ => Err(format!(
+                "unknown repeat mode {:?}, expected twice, multi, exactly:K, at-least:K or pattern-len:A..=B",
+                s
+            )),
+        }
+    }
+}
+
+/// A repeat mode along with the radix IDs are written in
+#[derive(Clone, Debug, PartialEq)]
+pub struct RepeatPolicy {
+    pub mode: RepeatMode,
+    pub radix: u32,
+}
+
+impl RepeatPolicy {
+    pub fn new(mode: RepeatMode, radix: u32) -> Self {
+        assert!((2..=36).contains(&radix), "Radix must be between 2 and 36");
+        Self { mode, radix }
+    }
+}
+
+impl From<RepeatMode> for RepeatPolicy {
+    fn from(mode: RepeatMode) -> Self {
+        Self::new(mode, 10)
+    }
+}
@@ -0,0 +1,216 @@
+/*
+Invalid IDs are made of a pattern of `period` digits repeated to fill `len`
+digits, i.e. they're `pattern * multiplier` where the multiplier is a "repunit"
+with a 1 every `period` digits:
+
+    123123123 = 123 * 1001001
+
+So rather than testing every number in a range, we can find the first and last
+pattern whose repetition lands in the range and work with the arithmetic series
+in between.
+
+When several periods are allowed for the same length (e.g. 6 digits can repeat
+a 1, 2 or 3 digit pattern) the same ID can be built more than once: 111111 is
+1 * 111111, 11 * 10101 and 111 * 1001. Every ID with period `d` also has period
+`e` whenever `d` divides `e`, so only the largest periods matter, and the IDs
+shared by several periods are exactly those with their greatest common divisor
+as a period. Inclusion-exclusion over the largest periods then counts each ID
+once.
+
+None of this depends on base 10, so the same works for IDs written in any radix
+by swapping the powers of 10 for powers of the radix.
+*/
+use crate::RepeatPolicy;
+
+/// Patterns of one period whose repetition lands in a range
+struct Block {
+    multiplier: u128,
+    first: u128,
+    last: u128,
+}
+
+impl Block {
+    fn count(&self) -> u128 {
+        self.last - self.first + 1
+    }
+
+    fn sum(&self) -> u128 {
+        // One of the count or first + last is always even, halve it first to
+        // keep the product small
+        let (count, ends) = (self.count(), self.first + self.last);
+        let pairs = if count.is_multiple_of(2) {
+            count / 2 * ends
+        } else {
+            ends / 2 * count
+        };
+        pairs * self.multiplier
+    }
+
+    fn ids(&self) -> impl Iterator<Item = u64> + '_ {
+        (self.first..=self.last).map(|pattern| (pattern * self.multiplier) as u64)
+    }
+}
+
+pub(crate) fn pow(radix: u32, exp: u32) -> u128 {
+    (radix as u128).pow(exp)
+}
+
+/// Number of digits in `n` when written in `radix`
+pub(crate) fn digits(n: u64, radix: u32) -> u32 {
+    n.checked_ilog(radix as u64).unwrap_or(0) + 1
+}
+
+fn gcd(a: u32, b: u32) -> u32 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+/// Periods that make an ID of `len` digits invalid under `policy`, leaving out
+/// any that divide another (their IDs are already covered by the larger period).
+fn maximal_periods(len: u32, policy: &RepeatPolicy) -> Vec<u32> {
+    let periods = policy.mode.periods(len);
+    periods
+        .iter()
+        .filter(|&&d| !periods.iter().any(|&e| e != d && e.is_multiple_of(d)))
+        .copied()
+        .collect()
+}
+
+/// The `period` digit patterns that, repeated to `len` digits, land in `start..=end`
+fn block(radix: u32, len: u32, period: u32, start: u64, end: u64) -> Option<Block> {
+    let multiplier = (pow(radix, len) - 1) / (pow(radix, period) - 1);
+    let lo = (start as u128).max(pow(radix, len - 1));
+    let hi = (end as u128).min(pow(radix, len) - 1);
+    let first = pow(radix, period - 1).max(lo.div_ceil(multiplier));
+    let last = (pow(radix, period) - 1).min(hi / multiplier);
+    (first <= last).then_some(Block {
+        multiplier,
+        first,
+        last,
+    })
+}
+
+/// Combine `f` over every invalid ID block in `start..=end`, using
+/// inclusion-exclusion so that each ID is only counted once
+fn aggregate(start: u64, end: u64, policy: &RepeatPolicy, f: impl Fn(&Block) -> u128) -> u128 {
+    if start > end {
+        return 0;
+    }
+    let radix = policy.radix;
+    let mut total: i128 = 0;
+    for len in digits(start, radix)..=digits(end, radix) {
+        let periods = maximal_periods(len, policy);
+        for subset in 1..1u32 << periods.len() {
+            let chosen = periods
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| subset & (1 << i) != 0)
+                .map(|(_, &d)| d);
+            let period = chosen.clone().fold(0, gcd);
+            let value = block(radix, len, period, start, end).map_or(0, |b| f(&b)) as i128;
+            if chosen.count() % 2 == 1 {
+                total += value;
+            } else {
+                total -= value;
+            }
+        }
+    }
+    total as u128
+}
+
+/// Sum of the invalid IDs in `start..=end`, without visiting each of them
+pub fn invalid_id_sum(start: u64, end: u64, policy: &RepeatPolicy) -> u128 {
+    aggregate(start, end, policy, Block::sum)
+}
+
+/// Number of invalid IDs in `start..=end`, without visiting each of them
+pub fn invalid_id_count(start: u64, end: u64, policy: &RepeatPolicy) -> u128 {
+    aggregate(start, end, policy, Block::count)
+}
+
+/// The invalid IDs in `start..=end`, in ascending order
+pub fn invalid_ids(start: u64, end: u64, policy: &RepeatPolicy) -> impl Iterator<Item = u64> {
+    let policy = policy.clone();
+    let radix = policy.radix;
+    // An empty range of lengths when the range of IDs is empty
+    let last_len = if start <= end { digits(end, radix) } else { 0 };
+    (digits(start, radix)..=last_len).flat_map(move |len| {
+        let mut ids: Vec<u64> = maximal_periods(len, &policy)
+            .into_iter()
+            .filter_map(|period| block(radix, len, period, start, end))
+            .flat_map(|b| b.ids().collect::<Vec<_>>())
+            .collect();
+        ids.sort_unstable();
+        ids.dedup();
+        ids
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{RepeatMode, is_invalid_id};
+
+    #[test]
+    fn test_maximal_periods() {
+        let periods = |len, mode: RepeatMode| maximal_periods(len, &mode.into());
+        assert_eq!(periods(6, RepeatMode::Twice), vec![3]);
+        assert_eq!(periods(7, RepeatMode::Twice), vec![]);
+        assert_eq!(periods(6, RepeatMode::Multi), vec![2, 3]);
+        assert_eq!(periods(8, RepeatMode::Multi), vec![4]);
+        assert_eq!(periods(1, RepeatMode::Multi), vec![]);
+        assert_eq!(periods(6, RepeatMode::Exactly(3)), vec![2]);
+        assert_eq!(periods(6, RepeatMode::Exactly(1)), vec![6]);
+        assert_eq!(periods(12, RepeatMode::AtLeast(3)), vec![3, 4]);
+        assert_eq!(periods(12, RepeatMode::PatternLen(1..=3)), vec![2, 3]);
+    }
+
+    #[test]
+    fn test_matches_brute_force() {
+        let modes = [
+            RepeatMode::Twice,
+            RepeatMode::Multi,
+            RepeatMode::Exactly(3),
+            RepeatMode::AtLeast(3),
+            RepeatMode::PatternLen(2..=3),
+        ];
+        let policies = modes.iter().cloned().map(RepeatPolicy::from).chain([
+            RepeatPolicy::new(RepeatMode::Twice, 2),
+            RepeatPolicy::new(RepeatMode::Multi, 16),
+            RepeatPolicy::new(RepeatMode::AtLeast(3), 3),
+        ]);
+        for policy in policies {
+            for (start, end) in [(0, 20_000), (95, 115), (998, 1012), (111_000, 112_000)] {
+                let expected: Vec<u64> = (start..=end)
+                    .filter(|&n| is_invalid_id(n, &policy))
+                    .collect();
+                assert_eq!(
+                    invalid_ids(start, end, &policy).collect::<Vec<_>>(),
+                    expected,
+                    "{:?}",
+                    policy
+                );
+                assert_eq!(
+                    invalid_id_sum(start, end, &policy),
+                    expected.iter().map(|&n| n as u128).sum::<u128>()
+                );
+                assert_eq!(
+                    invalid_id_count(start, end, &policy),
+                    expected.len() as u128
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_full_range() {
+        // Every even length up to 10 digits has 9 * 10^(len/2 - 1) invalid IDs
+        assert_eq!(
+            invalid_id_count(0, 9_999_999_999, &RepeatMode::Twice.into()),
+            99_999
+        );
+        assert!(invalid_id_sum(0, u64::MAX, &RepeatMode::Multi.into()) > 0);
+        assert!(invalid_id_sum(0, u64::MAX, &RepeatPolicy::new(RepeatMode::Multi, 2)) > 0);
+        assert_eq!(invalid_id_count(10, 1, &RepeatMode::Multi.into()), 0);
+        assert_eq!(invalid_ids(10, 1, &RepeatMode::Multi.into()).count(), 0);
+    }
+}
@@ -0,0 +1,90 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use common::{ParseError, ParseErrorKind};
+
+/// A bank of batteries, each labelled with a joltage between 0 and 9
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Bank(String);
+
+impl Bank {
+    /// Read a bank of at least `min_len` batteries, ignoring surrounding
+    /// whitespace
+    pub fn parse(line: &str, min_len: usize) -> Result<Self, ParseError> {
+        let bank = line.trim();
+        if let Some((idx, c)) = bank.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+            return Err(ParseError::new(
+                ParseErrorKind::Unexpected {
+                    expected: "a battery joltage between 0 and 9",
+                },
+                line,
+                &bank[idx..idx + c.len_utf8()],
+            ));
+        }
+        if bank.len() < min_len {
+            return Err(ParseError::new(
+                ParseErrorKind::TooShort {
+                    expected: "batteries",
+                    min: min_len,
+                },
+                line,
+                bank,
+            ));
+        }
+        Ok(Self(bank.to_string()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Joltage labels of the batteries, as ASCII digits
+    pub fn batteries(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// Reads a bank of at least one battery
+impl FromStr for Bank {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Bank::parse(s, 1)
+    }
+}
+
+impl Display for Bank {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Bank::parse(" 12345 ", 5).unwrap().as_str(), "12345");
+
+        let err = Bank::parse("12a45", 1).unwrap_err();
+        assert_eq!((err.column, err.text.as_str()), (3, "a"));
+
+        let err = Bank::parse("  1234", 5).unwrap_err();
+        assert_eq!((err.column, err.text.as_str()), (3, "1234"));
+        assert_eq!(
+            err.to_string(),
+            "1:3: expected at least 5 batteries: \"1234\""
+        );
+
+        assert!("".parse::<Bank>().is_err());
+    }
+}
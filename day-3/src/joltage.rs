@@ -0,0 +1,164 @@
+use std::cmp::Ordering;
+use std::fmt::{self, Display};
+use std::iter::Sum;
+use std::ops::Add;
+
+/// Each limb holds this many decimal digits
+const LIMB_DIGITS: usize = 18;
+const LIMB: u64 = 10u64.pow(LIMB_DIGITS as u32);
+
+/// A non-negative joltage of any number of digits, for when more batteries are
+/// turned on than fit in a `u64`
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Joltage {
+    // Base 10^18 limbs, least significant first, without leading zeros so
+    // that zero is empty
+    limbs: Vec<u64>,
+}
+
+impl Joltage {
+    /// Read decimal digits, most significant first
+    pub fn from_digits(digits: &[u8]) -> Self {
+        assert!(digits.iter().all(|&d| d < 10), "Digits must be below 10");
+        let limbs = digits
+            .rchunks(LIMB_DIGITS)
+            .map(|chunk| chunk.iter().fold(0, |acc, &d| acc * 10 + d as u64))
+            .collect();
+        let mut joltage = Self { limbs };
+        joltage.trim();
+        joltage
+    }
+
+    /// The joltage as a `u64`, if it fits
+    pub fn to_u64(&self) -> Option<u64> {
+        self.limbs
+            .iter()
+            .rev()
+            .try_fold(0u64, |acc, &limb| acc.checked_mul(LIMB)?.checked_add(limb))
+    }
+
+    fn trim(&mut self) {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+    }
+}
+
+impl From<u64> for Joltage {
+    fn from(mut n: u64) -> Self {
+        let mut limbs = Vec::new();
+        while n > 0 {
+            limbs.push(n % LIMB);
+            n /= LIMB;
+        }
+        Self { limbs }
+    }
+}
+
+impl Add for &Joltage {
+    type Output = Joltage;
+
+    fn add(self, other: &Joltage) -> Joltage {
+        let len = self.limbs.len().max(other.limbs.len());
+        let mut limbs = Vec::with_capacity(len + 1);
+        let mut carry = 0;
+        for i in 0..len {
+            let sum = self.limbs.get(i).unwrap_or(&0) + other.limbs.get(i).unwrap_or(&0) + carry;
+            limbs.push(sum % LIMB);
+            carry = sum / LIMB;
+        }
+        if carry > 0 {
+            limbs.push(carry);
+        }
+        Joltage { limbs }
+    }
+}
+
+impl Add for Joltage {
+    type Output = Joltage;
+
+    fn add(self, other: Joltage) -> Joltage {
+        &self + &other
+    }
+}
+
+impl Sum for Joltage {
+    fn sum<I: Iterator<Item = Joltage>>(iter: I) -> Self {
+        iter.fold(Joltage::default(), |acc, j| &acc + &j)
+    }
+}
+
+impl Ord for Joltage {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for Joltage {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for Joltage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some((most, rest)) = self.limbs.split_last() else {
+            return f.pad("0");
+        };
+        let mut s = most.to_string();
+        for limb in rest.iter().rev() {
+            s.push_str(&format!("{:0width$}", limb, width = LIMB_DIGITS));
+        }
+        f.pad(&s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_digits() {
+        assert_eq!(Joltage::from_digits(&[0, 0, 4, 2]).to_string(), "42");
+        assert_eq!(Joltage::from_digits(&[0, 0]), Joltage::default());
+        assert_eq!(Joltage::default().to_string(), "0");
+        assert_eq!(Joltage::from(1234), Joltage::from_digits(&[1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn test_add() {
+        assert_eq!((Joltage::from(999) + Joltage::from(1)).to_string(), "1000");
+        let big = Joltage::from(u64::MAX) + Joltage::from(u64::MAX);
+        assert_eq!(big.to_string(), (u64::MAX as u128 * 2).to_string());
+        assert_eq!(big.to_u64(), None);
+        assert_eq!(
+            [5, 17, 978].into_iter().map(Joltage::from).sum::<Joltage>(),
+            Joltage::from(1000)
+        );
+    }
+
+    #[test]
+    fn test_many_digits() {
+        let digits: Vec<u8> = (0..50).map(|i| (i % 9 + 1) as u8).collect();
+        let joltage = Joltage::from_digits(&digits);
+        let expected: String = digits.iter().map(|&d| char::from(b'0' + d)).collect();
+        assert_eq!(joltage.to_string(), expected);
+        // Zeros within a limb are kept
+        let joltage = Joltage::from_digits(&[[1].as_slice(), &[0; 20]].concat());
+        assert_eq!(joltage.to_string(), format!("1{}", "0".repeat(20)));
+        assert_eq!(
+            (joltage + Joltage::from(1)).to_string(),
+            format!("1{}1", "0".repeat(19))
+        );
+    }
+
+    #[test]
+    fn test_ord() {
+        assert!(Joltage::from(100) > Joltage::from(99));
+        assert!(Joltage::from(91) > Joltage::from(19));
+        assert!(Joltage::from(0) < Joltage::from(1));
+    }
+}
@@ -0,0 +1,397 @@
+// https://adventofcode.com/2025/day/3
+
+/*
+NOTES:
+
+- batteries labelled with joltage, 1-9
+- batteries arranged in banks
+
+pt 1:
+- turn on 2 batteries in each bank, joltage equals number formed by those batteries joltage
+- find largest joltage of each bank
+
+pt 2:
+- turn on 12 batteries in each bank, joltage equals number formed by those batteries joltage
+- find largest joltage of each bank
+*/
+
+use std::path::Path;
+
+use common::{Error, ParseError, Part, Solution, read_input};
+
+mod bank;
+mod joltage;
+mod solver;
+
+pub use bank::Bank;
+pub use joltage::Joltage;
+pub use solver::{
+    Adjacency, Allocation, Constraints, Goal, allocate, select_with, total_joltage_with,
+};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<Bank>;
+    type Answer1 = Joltage;
+    type Answer2 = Joltage;
+
+    /// Read the banks, rejecting any too short for both parts
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_banks_for(input, Part::Both)
+    }
+
+    /// Total joltage with 2 batteries per bank
+    fn part1(banks: &Self::Input) -> Self::Answer1 {
+        solve_part(banks, 1)
+    }
+
+    /// Total joltage with 12 batteries per bank
+    fn part2(banks: &Self::Input) -> Self::Answer2 {
+        solve_part(banks, 2)
+    }
+}
+
+/// Batteries turned on in each bank for a part
+pub fn batteries_for(part: u8) -> u8 {
+    match part {
+        1 => 2,
+        _ => 12,
+    }
+}
+
+/// Total joltage for a part, from banks [`parse_banks_for`] checked are long
+/// enough for it
+pub fn solve_part(banks: &[Bank], part: u8) -> Joltage {
+    total_big_joltage(banks, batteries_for(part))
+        .expect("Banks were checked to hold enough batteries for the part")
+}
+
+/// Read one bank per line, rejecting banks too short for the parts being solved
+pub fn parse_banks_for(input: &str, part: Part) -> Result<Vec<Bank>, ParseError> {
+    let min_len = part.numbers().iter().map(|&p| batteries_for(p)).max();
+    parse_banks_of(input, min_len.unwrap_or(1) as usize)
+}
+
+/// Read one bank per line, rejecting anything other than battery joltages and
+/// empty banks
+pub fn parse_banks(input: &str) -> Result<Vec<Bank>, ParseError> {
+    parse_banks_of(input, 1)
+}
+
+/// Read one bank per line, rejecting banks of fewer than `min_len` batteries
+fn parse_banks_of(input: &str, min_len: usize) -> Result<Vec<Bank>, ParseError> {
+    input
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(|line| Bank::parse(line, min_len).map_err(|e| e.rebase(input, line)))
+        .collect()
+}
+
+pub fn get_banks<P>(filename: P) -> Result<Vec<Bank>, Error>
+where
+    P: AsRef<Path>,
+{
+    let input = read_input(&filename)?;
+    parse_banks(&input).map_err(|e| e.with_file(filename).into())
+}
+
+/// Sum of the largest joltage of each bank when turning on `n` batteries, or
+/// `None` when a bank has fewer than `n` batteries or the sum overflows a `u64`
+pub fn total_joltage(banks: &[Bank], n: u8) -> Option<u64> {
+    total_big_joltage(banks, n)?.to_u64()
+}
+
+/// Sum of the largest joltage of each bank when turning on `n` batteries, for
+/// any number of batteries. `None` when a bank has fewer than `n` batteries.
+pub fn total_big_joltage(banks: &[Bank], n: u8) -> Option<Joltage> {
+    banks
+        .iter()
+        .enumerate()
+        .map(|(i, bank)| {
+            let selection = select_batteries(bank, n)?;
+            log::info!(
+                "Bank {}: {}\n{}",
+                i + 1,
+                selection.joltage(),
+                selection.render(bank)
+            );
+            Some(selection.joltage())
+        })
+        .sum()
+}
+
+/// The batteries turned on in a bank
+#[derive(Clone, Debug, PartialEq)]
+pub struct Selection {
+    /// Positions of the batteries in the bank, in ascending order
+    pub indices: Vec<usize>,
+    /// Joltage of each battery turned on
+    pub digits: Vec<u8>,
+}
+
+impl Selection {
+    pub(crate) fn from_indices(batteries: &[u8], indices: Vec<usize>) -> Self {
+        let digits = indices.iter().map(|&i| batteries[i] - b'0').collect();
+        Self { indices, digits }
+    }
+    /// Joltage of the bank, the digits read as one number
+    pub fn joltage(&self) -> Joltage {
+        Joltage::from_digits(&self.digits)
+    }
+
+    /// Joltage of the bank as a `u64`, or `None` when it doesn't fit, which can
+    /// only happen beyond 19 batteries
+    pub fn value(&self) -> Option<u64> {
+        self.joltage().to_u64()
+    }
+
+    /// The bank with a caret under each battery turned on
+    pub fn render(&self, bank: &Bank) -> String {
+        let mut markers = vec![' '; bank.len()];
+        for &i in &self.indices {
+            markers[i] = '^';
+        }
+        let markers: String = markers.into_iter().collect();
+        format!("{}\n{}", bank, markers.trim_end())
+    }
+}
+
+/// Largest joltage from turning on `n` batteries of a bank, or `None` when the
+/// bank has fewer than `n` batteries or the joltage overflows a `u64`
+pub fn get_largest_joltage(bank: &Bank, n: u8) -> Option<u64> {
+    select_batteries(bank, n)?.value()
+}
+
+/// Turn on the `n` batteries of a bank giving the largest joltage, keeping
+/// their order.
+///
+/// Walks the bank once, keeping a stack of the batteries chosen so far: a
+/// battery replaces any smaller ones before it for as long as there are enough
+/// batteries left to skip, since a larger leading digit always wins.
+///
+/// `None` when the bank has fewer than `n` batteries.
+pub fn select_batteries(bank: &Bank, n: u8) -> Option<Selection> {
+    let batteries = bank.batteries();
+    let n = n as usize;
+    log::debug!("Bank {}, need {} vals", bank, n);
+
+    let mut skips = batteries.len().checked_sub(n)?;
+    let mut indices = Vec::<usize>::with_capacity(batteries.len());
+    for (i, &battery) in batteries.iter().enumerate() {
+        while skips > 0
+            && indices
+                .last()
+                .is_some_and(|&last| batteries[last] < battery)
+        {
+            let skipped = indices.pop().expect("Stack is not empty");
+            log::trace!(
+                " skipping {} at {} for {} at {}",
+                batteries[skipped] as char,
+                skipped,
+                battery as char,
+                i
+            );
+            skips -= 1;
+        }
+        indices.push(i);
+    }
+    indices.truncate(n);
+
+    let selection = Selection::from_indices(batteries, indices);
+    log::debug!(" -> {}", selection.joltage());
+    Some(selection)
+}
+
+#[cfg(test)]
+mod tests {
+    use common::ParseErrorKind;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn bank(s: &str) -> Bank {
+        s.parse().unwrap()
+    }
+
+    /// The original implementation, rescanning a window for every digit
+    fn windowed_largest_joltage(bank: String, n: u8) -> u64 {
+        /*
+        Let's get the top 3 numbers out of this bank: 111111
+        1111XX - 1st number (i=0) must ignore first 0 values (i) & ignore last 2 values (n - i - 1)
+        X1111X - 2nd number (i=1) must ignore first 1 values (i) & ignore last 1 values (n - i - 1)
+        XX1111 - 3rd number (i=2) must ignore first 2 values (i) & ignore last 0 values (n - i - 1)
+        We also must ignore any values that are before or equal to the last selected index by starting at last_selected_idx + 1
+        */
+        let mut max_vals = Vec::<u8>::new();
+        let mut last_selected_idx: isize = -1;
+        log::debug!("Bank {}, need {} vals", bank, n);
+        for i in 0..n {
+            let start = (i as usize).max((last_selected_idx + 1) as usize);
+            let end = bank.len() - (n - i) as usize;
+            log::trace!(
+                "Searching {} for char {}/{} between indexes {} and {}",
+                bank,
+                i,
+                n,
+                start,
+                end
+            );
+            let search_subset = &bank[start..=end];
+            log::trace!("  searching subset: \"{}\"", search_subset);
+            for (mut j, c) in search_subset.chars().enumerate() {
+                j += start;
+                let val = c.to_string().parse::<u8>().expect("Failed to parse value");
+
+                // Just put something in store on first pass
+                if max_vals.len() == i as usize {
+                    log::trace!(
+                        " char {}/{}: using \"{}\" as we don't have a value",
+                        i,
+                        n,
+                        val
+                    );
+                    max_vals.push(val);
+                    last_selected_idx = j as isize;
+                    // Otherwise, only replace if new val is larger than stored value
+                } else if val > max_vals[i as usize] {
+                    log::trace!(
+                        " char {}/{}: using \"{}\" as it's larger than \"{}\"",
+                        i,
+                        n,
+                        val,
+                        max_vals[i as usize]
+                    );
+                    max_vals[i as usize] = val;
+                    last_selected_idx = j as isize;
+                } else {
+                    log::trace!(
+                        " char {}/{}: ignoring \"{}\", as it's smaller than \"{}\"",
+                        i,
+                        n,
+                        val,
+                        max_vals[i as usize]
+                    );
+                }
+            }
+            log::trace!(
+                " char {}/{} FINAL: {} (ie {:?})",
+                i,
+                n,
+                max_vals[i as usize],
+                max_vals
+            );
+        }
+        let s: String = max_vals.iter().map(|&c| format!("{}", c)).collect();
+        log::debug!(" -> {}", s);
+        s.parse::<u64>().expect("Failed to parse")
+    }
+
+    proptest! {
+        #[test]
+        fn prop_matches_windowed(bank in "[0-9]{1,40}", n in 1u8..=12) {
+            prop_assume!(bank.len() >= n as usize);
+            prop_assert_eq!(
+                get_largest_joltage(&self::bank(&bank), n),
+                Some(windowed_largest_joltage(bank, n))
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_banks() {
+        assert_eq!(
+            parse_banks("987654321111111\n811111111111119\n").unwrap(),
+            vec![bank("987654321111111"), bank("811111111111119")]
+        );
+        let err = parse_banks("987654321111111\n8111111a1111119\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 8, "a"));
+        assert_eq!(parse_banks("12345\n").unwrap(), vec![bank("12345")]);
+        // Banks only need enough batteries for the parts being solved
+        assert_eq!(
+            parse_banks_for("12345\n", Part::One).unwrap(),
+            vec![bank("12345")]
+        );
+        assert!(parse_banks_for("12345\n", Part::Two).is_err());
+        assert!(parse_banks_for("1\n", Part::One).is_err());
+        let err = Day3::parse("987654321111111\n 8111111111\n").unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.text.as_str()),
+            (2, 2, "8111111111")
+        );
+        assert!(matches!(err.kind, ParseErrorKind::TooShort { min: 12, .. }));
+    }
+
+    #[test]
+    fn test_select_batteries() {
+        let bank = bank("818181911112111");
+        let selection = select_batteries(&bank, 2).unwrap();
+        assert_eq!(selection.indices, vec![6, 11]);
+        assert_eq!(selection.digits, vec![9, 2]);
+        assert_eq!(selection.value(), Some(92));
+        assert_eq!(selection.render(&bank), "818181911112111\n      ^    ^");
+    }
+
+    #[test]
+    fn test_short_banks() {
+        let short = bank("12345");
+        assert_eq!(select_batteries(&short, 6), None);
+        assert_eq!(get_largest_joltage(&short, 12), None);
+        assert_eq!(get_largest_joltage(&short, 5), Some(12345));
+        let banks = vec![short];
+        assert_eq!(total_joltage(&banks, 2), Some(45));
+        assert_eq!(total_joltage(&banks, 6), None);
+        assert_eq!(total_big_joltage(&banks, 6), None);
+
+        let long = bank(&"9".repeat(25));
+        assert_eq!(get_largest_joltage(&long, 20), None);
+        assert_eq!(select_batteries(&long, 20).unwrap().value(), None);
+        assert_eq!(total_joltage(&[long], 20), None);
+    }
+
+    #[test]
+    fn test_total_big_joltage() {
+        let banks = vec![bank("987654321111111"), bank("811111111111119")];
+        assert_eq!(
+            total_big_joltage(&banks, 12),
+            total_joltage(&banks, 12).map(Joltage::from)
+        );
+
+        // 50 nines plus one
+        let banks = vec![bank(&"9".repeat(60)), bank(&("0".repeat(49) + "1"))];
+        assert_eq!(
+            total_big_joltage(&banks, 50).unwrap().to_string(),
+            format!("1{}", "0".repeat(50))
+        );
+    }
+
+    #[test]
+    fn test_get_largest_joltage_pt1() {
+        assert_eq!(get_largest_joltage(&bank("987654321111111"), 2), Some(98));
+        assert_eq!(get_largest_joltage(&bank("811111111111119"), 2), Some(89));
+        assert_eq!(get_largest_joltage(&bank("234234234234278"), 2), Some(78));
+        assert_eq!(get_largest_joltage(&bank("818181911112111"), 2), Some(92));
+    }
+
+    #[test]
+    fn test_get_largest_joltage_pt2() {
+        assert_eq!(
+            get_largest_joltage(&bank("987654321111111"), 12),
+            Some(987654321111)
+        );
+        assert_eq!(
+            get_largest_joltage(&bank("811111111111119"), 12),
+            Some(811111111119)
+        );
+        assert_eq!(
+            get_largest_joltage(&bank("234234234234278"), 12),
+            Some(434234234278)
+        );
+        assert_eq!(
+            get_largest_joltage(&bank("818181911112111"), 12),
+            Some(888911112111)
+        );
+    }
+}
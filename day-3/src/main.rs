@@ -1,124 +1,100 @@
 // https://adventofcode.com/2025/day/3
+use std::process::ExitCode;
+use std::time::Instant;
 
-/*
-NOTES:
+use clap::{ArgGroup, Parser};
+use common::Solution;
+use common::cli::{Args, Verbosity};
+use common::output::Printer;
+use day_3::{
+    Adjacency, Constraints, Day3, Goal, allocate, parse_banks, parse_banks_for, solve_part,
+    total_joltage_with,
+};
 
-- batteries labelled with joltage, 1-9
-- batteries arranged in banks
+/// Solve day 3 of Advent of Code 2025
+#[derive(Parser)]
+#[command(group(ArgGroup::new("what_if").args(["batteries", "budget"])))]
+struct Cli {
+    #[command(flatten)]
+    args: Args,
 
-pt 1:
-- turn on 2 batteries in each bank, joltage equals number formed by those batteries joltage
-- find largest joltage of each bank
+    #[command(flatten)]
+    verbosity: Verbosity,
 
-pt 2:
-- turn on 12 batteries in each bank, joltage equals number formed by those batteries joltage
-- find largest joltage of each bank
-*/
+    /// Total the joltage from turning on this many batteries in each bank
+    /// instead of solving the parts, any number of batteries is allowed
+    #[arg(short, long, value_name = "N", conflicts_with_all = ["part", "format"])]
+    batteries: Option<usize>,
 
-use std::{
-    fs::File,
-    io::{BufRead, BufReader, Lines, Result},
-    path::Path,
-};
+    /// Share this many batteries across all banks for the best total instead
+    /// of solving the parts
+    #[arg(long, value_name = "N", conflicts_with_all = ["part", "format"])]
+    budget: Option<usize>,
 
-fn main() {
-    let banks = get_banks("input.txt").expect("Failed to read banks");
-    let battery_count = 12;
-    let sum = banks
-        .map(|bank| get_largest_joltage(bank.expect("Failed to read line"), battery_count))
-        .sum::<u64>();
-    println!("{}", sum);
-}
+    /// Whether to look for the largest or smallest joltage
+    #[arg(long, value_enum, default_value_t = Goal::Maximize, requires = "what_if")]
+    goal: Goal,
 
-fn get_banks<P>(filename: P) -> Result<Lines<BufReader<File>>>
-where
-    P: AsRef<Path>,
-{
-    let file = File::open(filename)?;
-    Ok(BufReader::new(file).lines())
+    /// Which batteries may be turned on next to each other
+    #[arg(long, value_enum, default_value_t = Adjacency::Any, requires = "what_if")]
+    adjacency: Adjacency,
 }
 
-fn get_largest_joltage(bank: String, n: u8) -> u64 {
-    /*
-    Let's get the top 3 numbers out of this bank: 111111
-    1111XX - 1st number (i=0) must ignore first 0 values (i) & ignore last 2 values (n - i - 1)
-    X1111X - 2nd number (i=1) must ignore first 1 values (i) & ignore last 1 values (n - i - 1)
-    XX1111 - 3rd number (i=2) must ignore first 2 values (i) & ignore last 0 values (n - i - 1)
-    We also must ignore any values that are before or equal to the last selected index by starting at last_selected_idx + 1
-    */
-    let mut max_vals = Vec::<u8>::new();
-    let mut last_selected_idx: isize = -1;
-    println!("Bank {}, need {} vals", bank, n);
-    for i in 0..n {
-        let start = (i as usize).max((last_selected_idx + 1) as usize);
-        let end = bank.len() - (n - i) as usize;
-        println!(
-            "Searching {} for char {}/{} between indexes {} and {}",
-            bank, i, n, start, end
-        );
-        let search_subset = &bank[start..=end];
-        println!("  searching subset: \"{}\"", search_subset.to_string());
-        for (mut j, c) in search_subset.chars().into_iter().enumerate() {
-            j = j + start;
-            let val = c.to_string().parse::<u8>().expect("Failed to parse value");
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    cli.verbosity.init_logging();
 
-            // Just put something in store on first pass
-            print!(" char {}/{}: ", i, n);
-            if max_vals.len() == i as usize {
-                println!("using \"{}\" as we don't have a value", val);
-                max_vals.push(val);
-                last_selected_idx = j as isize;
-                // Otherwise, only replace if new val is larger than stored value
-            } else if val > max_vals[i as usize] {
-                println!(
-                    "using \"{}\" as it's larger than \"{}\"",
-                    val, max_vals[i as usize]
-                );
-                max_vals[i as usize] = val;
-                last_selected_idx = j as isize;
-            } else {
-                println!(
-                    "ignoring \"{}\", as it's smaller than \"{}\"",
-                    val, max_vals[i as usize]
-                );
-            }
-        }
-        println!(" char {}/{} FINAL: {} (ie {:?})", i, n, max_vals[i as usize], max_vals);
+    if cli.batteries.is_none() && cli.budget.is_none() {
+        return solve(&cli.args);
     }
-    let s: String = max_vals.iter().map(|&c| format!("{}", c)).collect();
-    println!(" -> {}", s);
-    s.parse::<u64>().expect("Failed to parse")
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
+    // Banks only need as many batteries as are asked of them, not enough for
+    // both parts
+    let banks = match common::cli::load_with(&cli.args, parse_banks) {
+        Ok(banks) => banks,
+        Err(code) => return code,
+    };
+    let constraints = Constraints {
+        goal: cli.goal,
+        adjacency: cli.adjacency,
+    };
 
-    #[test]
-    fn test_get_largest_joltage_pt1() {
-        assert_eq!(get_largest_joltage("987654321111111".into(), 2), 98);
-        assert_eq!(get_largest_joltage("811111111111119".into(), 2), 89);
-        assert_eq!(get_largest_joltage("234234234234278".into(), 2), 78);
-        assert_eq!(get_largest_joltage("818181911112111".into(), 2), 92);
+    if let Some(n) = cli.batteries {
+        let Some(total) = total_joltage_with(&banks, n, &constraints) else {
+            eprintln!("error: not every bank can turn on {} batteries", n);
+            return ExitCode::FAILURE;
+        };
+        println!("Day {} joltage with {} batteries: {}", Day3::DAY, n, total);
     }
-
-    #[test]
-    fn test_get_largest_joltage_pt2() {
-        assert_eq!(
-            get_largest_joltage("987654321111111".into(), 12),
-            987654321111
-        );
-        assert_eq!(
-            get_largest_joltage("811111111111119".into(), 12),
-            811111111119
-        );
-        assert_eq!(
-            get_largest_joltage("234234234234278".into(), 12),
-            434234234278
-        );
-        assert_eq!(
-            get_largest_joltage("818181911112111".into(), 12),
-            888911112111
+    if let Some(budget) = cli.budget {
+        let Some(allocation) = allocate(&banks, budget, &constraints) else {
+            eprintln!("error: the banks can't turn on {} batteries", budget);
+            return ExitCode::FAILURE;
+        };
+        for (i, count) in allocation.counts.iter().enumerate() {
+            log::info!("Bank {}: {} batteries", i + 1, count);
+        }
+        println!(
+            "Day {} joltage with a budget of {} batteries: {}",
+            Day3::DAY,
+            budget,
+            allocation.total
         );
     }
+    ExitCode::SUCCESS
+}
+
+/// Solve the requested parts, only requiring the banks to be long enough for
+/// those parts
+fn solve(args: &Args) -> ExitCode {
+    let start = Instant::now();
+    let banks = match common::cli::load_with(args, |input| parse_banks_for(input, args.part)) {
+        Ok(banks) => banks,
+        Err(code) => return code,
+    };
+    let parse_elapsed = start.elapsed();
+    let solved = common::solve_parts(Day3::DAY, parse_elapsed, args.part, |part| {
+        solve_part(&banks, part).to_string()
+    });
+    Printer::new(args.format).print(&solved);
+    ExitCode::SUCCESS
 }
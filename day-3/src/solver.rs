@@ -0,0 +1,243 @@
+/*
+The parts only ever want the largest joltage from a fixed number of batteries,
+which the stack in `select_batteries` finds in one pass. The other modes use a
+slower greedy pick instead: with the number of batteries fixed, comparing
+joltages is comparing their digits in order, so each battery in turn should be
+the best one that still leaves room for the rest. Taking the earliest of equally
+good batteries leaves the most room.
+
+Sharing a budget across banks can't be done bank by bank, so it's a knapsack:
+for every bank and every count of batteries spent so far, keep the best total.
+*/
+use clap::ValueEnum;
+
+use crate::{Bank, Joltage, Selection, select_batteries};
+
+/// Whether to look for the largest or the smallest joltage
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Goal {
+    #[default]
+    #[value(name = "max")]
+    Maximize,
+    #[value(name = "min")]
+    Minimize,
+}
+
+/// Which batteries may be turned on next to each other
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Adjacency {
+    /// Any batteries
+    #[default]
+    Any,
+    /// Only a single run of neighboring batteries
+    Require,
+    /// No two neighboring batteries
+    Forbid,
+}
+
+/// Rules for which batteries of a bank are turned on
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Constraints {
+    pub goal: Goal,
+    pub adjacency: Adjacency,
+}
+
+impl Goal {
+    /// Whether `candidate` digits beat the `best` so far
+    fn prefers(self, candidate: &[u8], best: &[u8]) -> bool {
+        match self {
+            Goal::Maximize => candidate > best,
+            Goal::Minimize => candidate < best,
+        }
+    }
+}
+
+/// Turn on `n` batteries of a bank following `constraints`, or `None` when the
+/// bank can't fit them
+pub fn select_with(bank: &Bank, n: usize, constraints: &Constraints) -> Option<Selection> {
+    let batteries = bank.batteries();
+    let indices = match constraints.adjacency {
+        Adjacency::Any if constraints.goal == Goal::Maximize && n <= u8::MAX as usize => {
+            return select_batteries(bank, n as u8);
+        }
+        Adjacency::Any => greedy(batteries, n, 1, constraints.goal)?,
+        Adjacency::Forbid => greedy(batteries, n, 2, constraints.goal)?,
+        Adjacency::Require => {
+            let start = (0..(batteries.len() + 1).checked_sub(n)?).reduce(|best, start| {
+                let window = |s: usize| &batteries[s..s + n];
+                if constraints.goal.prefers(window(start), window(best)) {
+                    start
+                } else {
+                    best
+                }
+            })?;
+            (start..start + n).collect()
+        }
+    };
+    Some(Selection::from_indices(batteries, indices))
+}
+
+/// Pick `n` batteries at least `gap` apart, each the best that leaves room for
+/// the ones after it
+fn greedy(batteries: &[u8], n: usize, gap: usize, goal: Goal) -> Option<Vec<usize>> {
+    if n == 0 {
+        return Some(Vec::new());
+    }
+    // The batteries need (n - 1) * gap + 1 positions, so the first can go no
+    // later than this
+    let last_start = batteries.len().checked_sub((n - 1) * gap + 1)?;
+    let mut indices = Vec::with_capacity(n);
+    let mut lo = 0;
+    for k in 0..n {
+        let hi = last_start + k * gap;
+        let pick = (lo..=hi).reduce(|best, i| {
+            if goal.prefers(&batteries[i..=i], &batteries[best..=best]) {
+                i
+            } else {
+                best
+            }
+        })?;
+        indices.push(pick);
+        lo = pick + gap;
+    }
+    Some(indices)
+}
+
+/// Total joltage of every bank with `n` batteries turned on in each, or `None`
+/// when a bank can't fit them
+pub fn total_joltage_with(banks: &[Bank], n: usize, constraints: &Constraints) -> Option<Joltage> {
+    banks
+        .iter()
+        .map(|bank| select_with(bank, n, constraints).map(|s| s.joltage()))
+        .sum()
+}
+
+/// How a budget of batteries is shared across banks
+#[derive(Clone, Debug, PartialEq)]
+pub struct Allocation {
+    /// Batteries turned on in each bank
+    pub counts: Vec<usize>,
+    pub total: Joltage,
+}
+
+/// Share exactly `budget` batteries across all banks, each bank turning on any
+/// number of them (including none), for the best total joltage. `None` when the
+/// banks can't fit the whole budget.
+pub fn allocate(banks: &[Bank], budget: usize, constraints: &Constraints) -> Option<Allocation> {
+    // best[b] is the best total of the banks so far with b batteries turned on,
+    // and choices[i][b] how many of those were in bank i
+    let mut best: Vec<Option<Joltage>> = vec![None; budget + 1];
+    best[0] = Some(Joltage::default());
+    let mut choices: Vec<Vec<usize>> = Vec::with_capacity(banks.len());
+
+    for bank in banks {
+        let options: Vec<(usize, Joltage)> = (0..=budget.min(bank.len()))
+            .filter_map(|k| Some((k, select_with(bank, k, constraints)?.joltage())))
+            .collect();
+        let mut next: Vec<Option<Joltage>> = vec![None; budget + 1];
+        let mut chosen = vec![0; budget + 1];
+        for (spent, total) in best.iter().enumerate() {
+            let Some(total) = total else {
+                continue;
+            };
+            for (k, joltage) in options.iter().take_while(|(k, _)| spent + k <= budget) {
+                let candidate = total + joltage;
+                let better = match &next[spent + k] {
+                    None => true,
+                    Some(current) => match constraints.goal {
+                        Goal::Maximize => candidate > *current,
+                        Goal::Minimize => candidate < *current,
+                    },
+                };
+                if better {
+                    next[spent + k] = Some(candidate);
+                    chosen[spent + k] = *k;
+                }
+            }
+        }
+        best = next;
+        choices.push(chosen);
+    }
+
+    let total = best[budget].take()?;
+    let mut counts = vec![0; banks.len()];
+    let mut remaining = budget;
+    for (i, chosen) in choices.iter().enumerate().rev() {
+        counts[i] = chosen[remaining];
+        remaining -= counts[i];
+    }
+    Some(Allocation { counts, total })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn joltage(bank: &str, n: usize, goal: Goal, adjacency: Adjacency) -> Option<String> {
+        let bank = bank.parse().unwrap();
+        select_with(&bank, n, &Constraints { goal, adjacency }).map(|s| s.joltage().to_string())
+    }
+
+    #[test]
+    fn test_select_with() {
+        use Adjacency::*;
+        use Goal::*;
+
+        assert_eq!(joltage("818181911112111", 2, Maximize, Any).unwrap(), "92");
+        assert_eq!(joltage("818181911112111", 2, Minimize, Any).unwrap(), "11");
+        assert_eq!(
+            joltage("818181911112111", 3, Maximize, Require).unwrap(),
+            "911"
+        );
+        assert_eq!(
+            joltage("818181911112111", 3, Minimize, Require).unwrap(),
+            "111"
+        );
+        assert_eq!(
+            joltage("818181911112111", 3, Maximize, Forbid).unwrap(),
+            "921"
+        );
+        assert_eq!(joltage("987654321", 3, Minimize, Forbid).unwrap(), "531");
+        assert_eq!(joltage("12345", 3, Maximize, Forbid).unwrap(), "135");
+        assert_eq!(joltage("12345", 4, Maximize, Forbid), None);
+        assert_eq!(joltage("12345", 6, Maximize, Require), None);
+        assert_eq!(joltage("12345", 0, Minimize, Any).unwrap(), "0");
+    }
+
+    #[test]
+    fn test_forbid_is_spread_out() {
+        let constraints = Constraints {
+            goal: Goal::Maximize,
+            adjacency: Adjacency::Forbid,
+        };
+        let selection = select_with(&"999999".parse().unwrap(), 3, &constraints).unwrap();
+        assert_eq!(selection.indices, vec![0, 2, 4]);
+    }
+
+    #[test]
+    fn test_allocate() {
+        let banks = vec!["19".parse().unwrap(), "987".parse().unwrap()];
+        let constraints = Constraints::default();
+
+        // Every battery in the longer bank beats splitting them
+        let allocation = allocate(&banks, 3, &constraints).unwrap();
+        assert_eq!(allocation.counts, vec![0, 3]);
+        assert_eq!(allocation.total, Joltage::from(987));
+
+        let allocation = allocate(&banks, 2, &constraints).unwrap();
+        assert_eq!(allocation.counts, vec![0, 2]);
+        assert_eq!(allocation.total, Joltage::from(98));
+
+        let allocation = allocate(&banks, 4, &constraints).unwrap();
+        assert_eq!(allocation.total, Joltage::from(987 + 9));
+
+        let minimise = Constraints {
+            goal: Goal::Minimize,
+            ..Constraints::default()
+        };
+        let allocation = allocate(&banks, 2, &minimise).unwrap();
+        assert_eq!(allocation.total, Joltage::from(1 + 7));
+
+        assert_eq!(allocate(&banks, 6, &constraints), None);
+    }
+}
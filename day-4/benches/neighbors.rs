@@ -0,0 +1,47 @@
+use std::hint::black_box;
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use day_4::{BitGrid, Rules, get_neighbors, random_grid};
+
+fn neighbor_counts(c: &mut Criterion) {
+    let rules = Rules::default();
+    let mut group = c.benchmark_group("neighbor_counts");
+    for size in [256, 1024, 4096] {
+        let grid = random_grid(size, size, 2025);
+        group.bench_with_input(BenchmarkId::new("get_neighbors", size), &grid, |b, grid| {
+            b.iter(|| {
+                grid.positions()
+                    .map(|(r, c)| get_neighbors(grid, &rules, r, c))
+                    .sum::<u32>()
+            })
+        });
+        let bits = BitGrid::from(&grid);
+        group.bench_with_input(BenchmarkId::new("bitset", size), &bits, |b, bits| {
+            b.iter(|| {
+                (0..bits.height())
+                    .map(|r| bits.row_neighbor_counts(r).iter().sum::<u32>())
+                    .sum::<u32>()
+            })
+        });
+    }
+    group.finish();
+}
+
+fn accessible(c: &mut Criterion) {
+    let rules = Rules::default();
+    let mut group = c.benchmark_group("accessible");
+    for size in [256, 1024, 4096] {
+        let grid = random_grid(size, size, 2025);
+        group.bench_with_input(BenchmarkId::new("grid", size), &grid, |b, grid| {
+            b.iter(|| day_4::accessible_rolls_with(black_box(grid), &rules, false))
+        });
+        let bits = BitGrid::from(&grid);
+        group.bench_with_input(BenchmarkId::new("bitset", size), &bits, |b, bits| {
+            b.iter(|| black_box(bits).accessible_count(rules.threshold))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, neighbor_counts, accessible);
+criterion_main!(benches);
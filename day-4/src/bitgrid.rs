@@ -0,0 +1,227 @@
+/*
+Each row is packed into 64-bit words, so a word holds 64 cells. Shifting a row
+one bit left or right lines every cell up with its west or east neighbor, and
+the rows above and below line up as they are. Adding those eight shifted rows
+with a bit-sliced adder counts the neighbors of 64 cells at once: plane `i`
+holds bit `i` of every cell's count.
+
+Only the puzzle's own rules are supported, the Moore neighborhood with nothing
+beyond the edges.
+*/
+use common::grid::Grid;
+
+/// Planes needed to count up to eight neighbors
+const PLANES: usize = 4;
+
+/// A grid of rolls packed one bit per cell
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BitGrid {
+    words: Vec<u64>,
+    width: usize,
+    height: usize,
+    stride: usize,
+}
+
+impl BitGrid {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, row_idx: usize, col_idx: usize) -> bool {
+        assert!(row_idx < self.height && col_idx < self.width);
+        self.row(row_idx)[col_idx / 64] >> (col_idx % 64) & 1 == 1
+    }
+
+    /// Number of rolls in the grid
+    pub fn count(&self) -> u32 {
+        self.words.iter().map(|w| w.count_ones()).sum()
+    }
+
+    fn row(&self, row_idx: usize) -> &[u64] {
+        &self.words[row_idx * self.stride..(row_idx + 1) * self.stride]
+    }
+
+    /// The neighbor counts of a whole row as bit planes, `stride` words each
+    fn row_planes(&self, row_idx: usize) -> [Vec<u64>; PLANES] {
+        let mut planes: [Vec<u64>; PLANES] = Default::default();
+        for plane in &mut planes {
+            *plane = vec![0; self.stride];
+        }
+
+        let above = row_idx.checked_sub(1).map(|r| self.row(r));
+        let below = (row_idx + 1 < self.height).then(|| self.row(row_idx + 1));
+        let current = self.row(row_idx);
+        for i in 0..self.stride {
+            let mut add = |x: u64| {
+                let mut carry = x;
+                for plane in &mut planes {
+                    let sum = plane[i] ^ carry;
+                    carry &= plane[i];
+                    plane[i] = sum;
+                }
+            };
+            for row in [above, below].into_iter().flatten() {
+                add(row[i]);
+                add(west(row, i));
+                add(east(row, i));
+            }
+            add(west(current, i));
+            add(east(current, i));
+        }
+        planes
+    }
+
+    /// The neighbor count of every cell in a row
+    pub fn row_neighbor_counts(&self, row_idx: usize) -> Vec<u32> {
+        let planes = self.row_planes(row_idx);
+        (0..self.width)
+            .map(|col_idx| {
+                let (word, bit) = (col_idx / 64, col_idx % 64);
+                (0..PLANES)
+                    .map(|p| ((planes[p][word] >> bit & 1) as u32) << p)
+                    .sum()
+            })
+            .collect()
+    }
+
+    /// The rolls of a row with fewer than `threshold` neighbors, as a mask
+    fn row_accessible(&self, row_idx: usize, threshold: u32) -> Vec<u64> {
+        let planes = self.row_planes(row_idx);
+        let current = self.row(row_idx);
+        if threshold >= 1 << PLANES {
+            return current.to_vec();
+        }
+        (0..self.stride)
+            .map(|i| {
+                // Compare against the threshold from the most significant
+                // plane down, as long as the higher planes are equal
+                let mut less = 0;
+                let mut equal = !0;
+                for (p, plane) in planes.iter().enumerate().rev() {
+                    let t = if threshold >> p & 1 == 1 { !0 } else { 0 };
+                    less |= equal & !plane[i] & t;
+                    equal &= !(plane[i] ^ t);
+                }
+                current[i] & less
+            })
+            .collect()
+    }
+
+    /// Number of rolls with fewer than `threshold` neighbors
+    pub fn accessible_count(&self, threshold: u32) -> u32 {
+        (0..self.height)
+            .flat_map(|row_idx| self.row_accessible(row_idx, threshold))
+            .map(|w| w.count_ones())
+            .sum()
+    }
+
+    /// Remove every roll with fewer than `threshold` neighbors at once,
+    /// returning how many were removed
+    pub fn remove_accessible(&mut self, threshold: u32) -> u32 {
+        let mask: Vec<u64> = (0..self.height)
+            .flat_map(|row_idx| self.row_accessible(row_idx, threshold))
+            .collect();
+        for (word, removed) in self.words.iter_mut().zip(&mask) {
+            *word &= !removed;
+        }
+        mask.iter().map(|w| w.count_ones()).sum()
+    }
+}
+
+/// The cells of `row` shifted one column east, so each bit holds its west
+/// neighbor
+fn west(row: &[u64], i: usize) -> u64 {
+    let carry = if i > 0 { row[i - 1] >> 63 } else { 0 };
+    row[i] << 1 | carry
+}
+
+/// The cells of `row` shifted one column west, so each bit holds its east
+/// neighbor
+fn east(row: &[u64], i: usize) -> u64 {
+    let carry = row.get(i + 1).map_or(0, |w| w << 63);
+    row[i] >> 1 | carry
+}
+
+impl From<&Grid<bool>> for BitGrid {
+    fn from(grid: &Grid<bool>) -> Self {
+        let (width, height) = (grid.width(), grid.height());
+        let stride = width.div_ceil(64);
+        let mut words = vec![0; stride * height];
+        for ((row_idx, col_idx), &cell) in grid.iter() {
+            if cell {
+                words[row_idx * stride + col_idx / 64] |= 1 << (col_idx % 64);
+            }
+        }
+        Self {
+            words,
+            width,
+            height,
+            stride,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Rules, build_grid, count_accessible_rolls, get_neighbors, random_grid};
+
+    #[test]
+    fn test_neighbor_counts() {
+        // Wider than a word, so neighbors are carried between words
+        let grid = random_grid(150, 20, 4);
+        let bits = BitGrid::from(&grid);
+        let rules = Rules::default();
+        for row_idx in 0..grid.height() {
+            let expected: Vec<u32> = (0..grid.width())
+                .map(|col_idx| get_neighbors(&grid, &rules, row_idx, col_idx))
+                .collect();
+            assert_eq!(
+                bits.row_neighbor_counts(row_idx),
+                expected,
+                "row {}",
+                row_idx
+            );
+        }
+    }
+
+    #[test]
+    fn test_accessible() {
+        let grid = random_grid(130, 70, 4);
+        for threshold in 0..10 {
+            let rules = Rules {
+                threshold,
+                ..Rules::default()
+            };
+            assert_eq!(
+                BitGrid::from(&grid).accessible_count(threshold),
+                count_accessible_rolls(grid.clone(), &rules, false),
+                "threshold {}",
+                threshold
+            );
+        }
+
+        let mut bits = BitGrid::from(&grid);
+        let mut removed = 0;
+        while let n @ 1.. = bits.remove_accessible(4) {
+            removed += n;
+        }
+        assert_eq!(
+            removed,
+            count_accessible_rolls(grid, &Rules::default(), true)
+        );
+    }
+
+    #[test]
+    fn test_get() {
+        let grid = build_grid("@.\n.@").unwrap();
+        let bits = BitGrid::from(&grid);
+        assert!(bits.get(0, 0) && bits.get(1, 1));
+        assert!(!bits.get(0, 1) && !bits.get(1, 0));
+        assert_eq!((bits.width(), bits.height(), bits.count()), (2, 2, 2));
+    }
+}
@@ -0,0 +1,331 @@
+use common::grid::Grid;
+use common::{ParseError, Solution};
+
+#[cfg(feature = "bitset")]
+mod bitgrid;
+mod removal;
+mod rules;
+mod visualize;
+
+#[cfg(feature = "bitset")]
+pub use bitgrid::BitGrid;
+pub use removal::{Timeline, Waves};
+use rules::Target;
+pub use rules::{Boundary, Neighborhood, Rules};
+pub use visualize::Frame;
+
+// https://adventofcode.com/2025/day/4
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Input = Grid<bool>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        build_grid(input)
+    }
+
+    /// Rolls accessible by a forklift in the initial grid
+    fn part1(grid: &Self::Input) -> Self::Answer1 {
+        #[cfg(feature = "bitset")]
+        return BitGrid::from(grid).accessible_count(Rules::default().threshold);
+        #[cfg(not(feature = "bitset"))]
+        count_accessible_rolls(grid.clone(), &Rules::default(), false)
+    }
+
+    /// Rolls removable by repeatedly taking every accessible roll
+    fn part2(grid: &Self::Input) -> Self::Answer2 {
+        #[cfg(feature = "bitset")]
+        {
+            let mut bits = BitGrid::from(grid);
+            let threshold = Rules::default().threshold;
+            std::iter::from_fn(|| Some(bits.remove_accessible(threshold)).filter(|&n| n > 0)).sum()
+        }
+        #[cfg(not(feature = "bitset"))]
+        count_accessible_rolls(grid.clone(), &Rules::default(), true)
+    }
+}
+
+/// Read a grid where each `@` is a roll of paper and each `.` is empty, every
+/// row as long as the first
+pub fn build_grid(input: impl AsRef<str>) -> Result<Grid<bool>, ParseError> {
+    Grid::parse_with(input.as_ref(), CELL, parse_cell)
+}
+
+/// Read a grid like [`build_grid`], extending rows shorter than the longest
+/// with rolls if `fill` is set or empty cells otherwise
+pub fn build_padded_grid(input: impl AsRef<str>, fill: bool) -> Result<Grid<bool>, ParseError> {
+    Grid::parse_padded(input.as_ref(), CELL, fill, parse_cell)
+}
+
+const CELL: &str = "a roll `@` or an empty cell `.`";
+
+/// Whether a cell holds a roll, or `None` if it isn't a cell at all
+pub fn parse_cell(c: char) -> Option<bool> {
+    match c {
+        '@' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    }
+}
+
+/// A pseudo-random grid with roughly 70% of cells holding a roll, always the
+/// same for a given `seed`. Used by the tests and benchmarks.
+#[cfg(any(test, feature = "bitset"))]
+#[doc(hidden)]
+pub fn random_grid(width: usize, height: usize, mut seed: u32) -> Grid<bool> {
+    let cells = (0..width * height)
+        .map(|_| {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            (seed >> 16) % 10 < 7
+        })
+        .collect();
+    Grid::new(width, cells)
+}
+
+/// Rolls neighboring a cell under `rules`
+pub fn get_neighbors(grid: &Grid<bool>, rules: &Rules, row_idx: usize, col_idx: usize) -> u32 {
+    count_neighbors(
+        grid,
+        &rules.neighborhood.offsets(),
+        rules.boundary,
+        (row_idx, col_idx),
+    )
+}
+
+pub(crate) fn count_neighbors(
+    grid: &Grid<bool>,
+    offsets: &[(isize, isize)],
+    boundary: Boundary,
+    position: (usize, usize),
+) -> u32 {
+    let size = (grid.height(), grid.width());
+    let mut cell_neighbors = 0;
+    for &offset in offsets {
+        match boundary.resolve(size, position, offset) {
+            Some(Target::Cell((y_i, x_i))) if grid[(y_i, x_i)] => {
+                cell_neighbors += 1;
+                log::trace!("Checking row {}, col {}: hit", y_i, x_i);
+            }
+            Some(Target::Cell((y_i, x_i))) => {
+                log::trace!("Checking row {}, col {}: miss", y_i, x_i);
+            }
+            Some(Target::Outside) => cell_neighbors += 1,
+            None => {}
+        }
+    }
+    cell_neighbors
+}
+
+pub fn get_accessible_rolls_count(
+    input: impl AsRef<str>,
+    remove_rolls: bool,
+) -> Result<u32, ParseError> {
+    Ok(count_accessible_rolls(
+        build_grid(input)?,
+        &Rules::default(),
+        remove_rolls,
+    ))
+}
+
+/// Count the accessible rolls of a grid under different `rules`, or with
+/// `remove_rolls` every roll removed by repeatedly taking the accessible ones
+pub fn accessible_rolls_with(grid: &Grid<bool>, rules: &Rules, remove_rolls: bool) -> u32 {
+    count_accessible_rolls(grid.clone(), rules, remove_rolls)
+}
+
+/// Count the accessible rolls, or with `remove_rolls` every roll removed by
+/// repeatedly taking all of the accessible ones at once
+fn count_accessible_rolls(grid: Grid<bool>, rules: &Rules, remove_rolls: bool) -> u32 {
+    let waves = Waves::new(grid, rules);
+    if !remove_rolls {
+        return waves.accessible().len() as u32;
+    }
+    waves.map(|wave| wave.len() as u32).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::ParseErrorKind;
+
+    #[test]
+    fn test_pt_1_ex() {
+        assert_eq!(
+            get_accessible_rolls_count(
+                "
+                ..@@.@@@@.
+                @@@.@.@.@@
+                @@@@@.@.@@
+                @.@@@@..@.
+                @@.@@@@.@@
+                .@@@@@@@.@
+                .@.@.@.@@@
+                @.@@@.@@@@
+                .@@@@@@@@.
+                @.@.@@@.@.
+                ",
+                false
+            )
+            .unwrap(),
+            13
+        );
+    }
+
+    #[test]
+    fn test_pt_2_ex() {
+        assert_eq!(
+            get_accessible_rolls_count(
+                "
+                ..@@.@@@@.
+                @@@.@.@.@@
+                @@@@@.@.@@
+                @.@@@@..@.
+                @@.@@@@.@@
+                .@@@@@@@.@
+                .@.@.@.@@@
+                @.@@@.@@@@
+                .@@@@@@@@.
+                @.@.@@@.@.
+                ",
+                true
+            )
+            .unwrap(),
+            43
+        );
+    }
+
+    /// The original algorithm, rescanning the whole grid after every wave
+    fn rescan_accessible_rolls(mut grid: Grid<bool>, rules: &Rules) -> u32 {
+        let mut accessible_count = 0;
+        loop {
+            let rolls_to_remove: Vec<_> = grid
+                .iter()
+                .filter(|&((row_idx, col_idx), &cell)| {
+                    cell && get_neighbors(&grid, rules, row_idx, col_idx) < rules.threshold
+                })
+                .map(|(position, _)| position)
+                .collect();
+            if rolls_to_remove.is_empty() {
+                break;
+            }
+            accessible_count += rolls_to_remove.len() as u32;
+            for position in rolls_to_remove {
+                grid[position] = false;
+            }
+        }
+        accessible_count
+    }
+
+    #[test]
+    fn test_matches_rescan() {
+        // A dense grid, so removals cascade over many waves
+        let grid = random_grid(60, 40, 2025);
+        let rules = [
+            Rules::default(),
+            Rules {
+                neighborhood: Neighborhood::VonNeumann,
+                threshold: 3,
+                boundary: Boundary::Wrap,
+            },
+            Rules {
+                neighborhood: Neighborhood::Radius(2),
+                threshold: 15,
+                boundary: Boundary::Occupied,
+            },
+            Rules {
+                neighborhood: Neighborhood::Custom(vec![(-1, 0), (0, 2), (2, 1)]),
+                threshold: 2,
+                boundary: Boundary::Wrap,
+            },
+        ];
+        for rules in rules {
+            assert_eq!(
+                count_accessible_rolls(grid.clone(), &rules, true),
+                rescan_accessible_rolls(grid.clone(), &rules),
+                "{:?}",
+                rules
+            );
+        }
+    }
+
+    #[test]
+    fn test_build_grid() {
+        let grid = build_grid(
+            "
+            ..@@.
+            @.@..
+            ",
+        )
+        .unwrap();
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            vec![
+                &[false, false, true, true, false],
+                &[true, false, true, false, false]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_build_grid_rejects() {
+        let err = build_grid("..@\n.x@").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, "x"));
+        assert_eq!(
+            err.kind,
+            ParseErrorKind::Unexpected {
+                expected: "a roll `@` or an empty cell `.`"
+            }
+        );
+
+        let err = build_grid("..@\n.@\n@@@").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, ".@"));
+    }
+
+    #[test]
+    fn test_build_padded_grid() {
+        let input = "..@\n.@\n@@@@";
+        let grid = build_padded_grid(input, false).unwrap();
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            vec![
+                &[false, false, true, false],
+                &[false, true, false, false],
+                &[true, true, true, true]
+            ]
+        );
+        let grid = build_padded_grid(input, true).unwrap();
+        assert_eq!(grid.row(1), &[false, true, true, true]);
+        assert!(build_padded_grid("@?", true).is_err());
+    }
+
+    #[test]
+    fn test_get_neighbors() {
+        let grid = build_grid(
+            "
+            ..@@.
+            @.@..
+            ",
+        )
+        .unwrap();
+        let rules = Rules::default();
+        assert_eq!(get_neighbors(&grid, &rules, 0, 0), 1);
+        assert_eq!(get_neighbors(&grid, &rules, 0, 2), 2);
+        assert_eq!(get_neighbors(&grid, &rules, 1, 3), 3);
+
+        let rules = Rules {
+            boundary: Boundary::Occupied,
+            ..Rules::default()
+        };
+        assert_eq!(get_neighbors(&grid, &rules, 0, 0), 6);
+        let rules = Rules {
+            neighborhood: Neighborhood::VonNeumann,
+            boundary: Boundary::Wrap,
+            ..Rules::default()
+        };
+        assert_eq!(get_neighbors(&grid, &rules, 0, 0), 2);
+    }
+}
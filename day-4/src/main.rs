@@ -1,165 +1,199 @@
-use std::fs::read_to_string;
-
 // https://adventofcode.com/2025/day/4
-fn main() {
-    env_logger::init();
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use clap::Parser;
+use common::Solution;
+use common::cli::{Args, Verbosity};
+use common::grid::Grid;
+use common::output::Printer;
+use day_4::{
+    Boundary, Day4, Frame, Neighborhood, Rules, Timeline, Waves, accessible_rolls_with,
+    build_padded_grid, parse_cell,
+};
+
+/// Solve day 4 of Advent of Code 2025
+#[derive(Parser)]
+struct Cli {
+    #[command(flatten)]
+    args: Args,
+
+    #[command(flatten)]
+    verbosity: Verbosity,
+
+    /// Cells counted as a roll's neighbors: von-neumann, moore, radius:K or
+    /// offsets:DR,DC;DR,DC;...
+    #[arg(long, default_value_t = Neighborhood::Moore)]
+    neighborhood: Neighborhood,
+
+    /// Rolls with fewer neighboring rolls than this are accessible
+    #[arg(short, long, default_value_t = 4)]
+    threshold: u32,
+
+    /// What lies beyond the edges of the grid
+    #[arg(short, long, value_enum, default_value_t = Boundary::Clamped)]
+    boundary: Boundary,
+
+    /// Extend rows shorter than the longest with this cell, `@` or `.`, rather
+    /// than rejecting them
+    #[arg(long, value_name = "CELL", value_parser = parse_fill)]
+    pad: Option<bool>,
+
+    /// Write the wave each roll is removed in to this file, as comma separated
+    /// rows, instead of solving the parts
+    #[arg(
+        long,
+        value_name = "PATH",
+        conflicts_with_all = ["part", "format", "visualize", "frames"]
+    )]
+    timeline: Option<PathBuf>,
+
+    /// Animate each wave of removals in the terminal instead of solving the
+    /// parts
+    #[arg(long, conflicts_with_all = ["part", "format"])]
+    visualize: bool,
 
-    let buffer = read_to_string("input.txt").expect("failed to read");
-    let accessible_rolls = get_accessible_rolls_count(buffer, true);
-    println!("Accessible: {}", accessible_rolls)
+    /// Pause between frames of the animation, in milliseconds
+    #[arg(long, default_value_t = 100, value_name = "MS", requires = "visualize")]
+    delay: u64,
+
+    /// Write each wave of removals as a PPM image to this directory instead of
+    /// solving the parts
+    #[arg(long, value_name = "DIR", conflicts_with_all = ["part", "format"])]
+    frames: Option<PathBuf>,
+
+    /// Pixels per cell in the PPM images
+    #[arg(
+        long,
+        default_value_t = 4,
+        value_parser = clap::value_parser!(u64).range(1..),
+        requires = "frames"
+    )]
+    scale: u64,
 }
 
-struct Grid(Vec<Vec<bool>>);
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    cli.verbosity.init_logging();
 
-impl Grid {
-    fn build(lines: impl AsRef<str>) -> Self {
-        let mut grid = Vec::new();
-        for line in lines.as_ref().trim().lines().map(|l| l.trim()) {
-            grid.push(line.chars().map(|c| c == '@').collect());
-        }
-        Self(grid)
+    let rules = Rules {
+        neighborhood: cli.neighborhood.clone(),
+        threshold: cli.threshold,
+        boundary: cli.boundary,
+    };
+    let solve = cli.timeline.is_none() && !cli.visualize && cli.frames.is_none();
+    if rules == Rules::default() && cli.pad.is_none() && solve {
+        return common::cli::run::<Day4>(&cli.args, &mut Printer::new(cli.args.format));
     }
 
-    fn get_neighbors(&self, row_idx: usize, col_idx: usize) -> u32 {
-        let mut cell_neighbors = 0;
-        let y_min = row_idx.saturating_sub(1);
-        let y_max = (row_idx + 1).min(self.0.len() - 1);
-        let x_min = col_idx.saturating_sub(1);
-        let x_max = (col_idx + 1).min(self.0[row_idx].len() - 1);
-        log::debug!(
-            "y_min={} y_max={}, x_min={} x_max={}",
-            y_min,
-            y_max,
-            x_min,
-            x_max,
-        );
+    let start = Instant::now();
+    let grid = match cli.pad {
+        Some(fill) => common::cli::load_with(&cli.args, |input| build_padded_grid(input, fill)),
+        None => common::cli::load::<Day4>(&cli.args),
+    };
+    let grid = match grid {
+        Ok(grid) => grid,
+        Err(code) => return code,
+    };
+    let parse_elapsed = start.elapsed();
 
-        for y_i in y_min..=y_max {
-            for x_i in x_min..=x_max {
-                if (y_i, x_i) == (row_idx, col_idx) {
-                    log::trace!("Skipping self (row {}, col {})", y_i, x_i);
-                    continue;
-                }
-                if self.0[y_i][x_i] {
-                    cell_neighbors += 1;
-                    log::trace!("Checking row {}, col {}: hit", y_i, x_i);
-                } else {
-                    log::trace!("Checking row {}, col {}: miss", y_i, x_i);
-                }
+    if cli.visualize || cli.frames.is_some() {
+        return match visualize(&cli, grid, &rules) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(e) => {
+                eprintln!("error: {}", e);
+                ExitCode::FAILURE
             }
-        }
-        cell_neighbors
+        };
     }
 
-    fn remove_roll(&mut self, row_idx: usize, col_idx: usize) {
-        self.0[row_idx][col_idx] = false;
-    }
-}
-
-fn get_accessible_rolls_count(input: impl AsRef<str>, remove_rolls: bool) -> u32 {
-    let mut grid = Grid::build(input);
-    let mut accessible_count = 0;
-    loop {
-        let mut rolls_to_remove = Vec::new();
-        for (row_idx, row) in grid.0.iter().enumerate() {
-            for (col_idx, cell) in row.iter().enumerate() {
-                if !cell {
-                    continue;
-                }
-                if grid.get_neighbors(row_idx, col_idx) >= 4 {
-                    continue;
-                }
-                if remove_rolls {
-                    rolls_to_remove.push((row_idx, col_idx));
-                }
-                accessible_count += 1
-            }
-        }
-        if rolls_to_remove.is_empty() || !remove_rolls {
-            break;
+    if let Some(path) = &cli.timeline {
+        let timeline = Timeline::new(&grid, &rules);
+        for (i, size) in timeline.wave_sizes().iter().enumerate() {
+            log::info!("Wave {}: {} rolls", i, size);
         }
-        for (row_idx, col_idx) in rolls_to_remove {
-            grid.remove_roll(row_idx, col_idx);
+        if let Err(e) = fs::write(path, timeline.export(&grid)) {
+            eprintln!("error: failed to write {}: {}", path.display(), e);
+            return ExitCode::FAILURE;
         }
+        println!(
+            "Day {} removed {} rolls in {} waves, timeline written to {}",
+            Day4::DAY,
+            timeline.removed_count(),
+            timeline.wave_count(),
+            path.display()
+        );
+        return ExitCode::SUCCESS;
     }
-    accessible_count
+    log::info!(
+        "Solving with the {} neighborhood, threshold {} and a {} boundary",
+        rules.neighborhood,
+        rules.threshold,
+        rules.boundary
+    );
+    let solved = common::solve_parts(Day4::DAY, parse_elapsed, cli.args.part, |part| {
+        accessible_rolls_with(&grid, &rules, part == 2).to_string()
+    });
+    Printer::new(cli.args.format).print(&solved);
+    ExitCode::SUCCESS
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_pt_1_ex() {
-        assert_eq!(
-            get_accessible_rolls_count(
-                "
-                ..@@.@@@@.
-                @@@.@.@.@@
-                @@@@@.@.@@
-                @.@@@@..@.
-                @@.@@@@.@@
-                .@@@@@@@.@
-                .@.@.@.@@@
-                @.@@@.@@@@
-                .@@@@@@@@.
-                @.@.@@@.@.
-                ",
-                false
-            ),
-            13
-        );
+fn parse_fill(s: &str) -> Result<bool, String> {
+    let mut chars = s.chars();
+    match (chars.next().and_then(parse_cell), chars.next()) {
+        (Some(fill), None) => Ok(fill),
+        _ => Err(format!("expected `@` or `.`, found {:?}", s)),
     }
+}
 
-    #[test]
-    fn test_pt_2_ex() {
-        assert_eq!(
-            get_accessible_rolls_count(
-                "
-                ..@@.@@@@.
-                @@@.@.@.@@
-                @@@@@.@.@@
-                @.@@@@..@.
-                @@.@@@@.@@
-                .@@@@@@@.@
-                .@.@.@.@@@
-                @.@@@.@@@@
-                .@@@@@@@@.
-                @.@.@@@.@.
-                ",
-                true
-            ),
-            43
-        );
+/// Show every wave of removals, highlighting the rolls each wave removes
+fn visualize(cli: &Cli, grid: Grid<bool>, rules: &Rules) -> Result<(), String> {
+    if let Some(dir) = &cli.frames {
+        fs::create_dir_all(dir)
+            .map_err(|e| format!("failed to create {}: {}", dir.display(), e))?;
     }
 
-    #[test]
-    fn test_build_grid() {
-        assert_eq!(
-            Grid::build(
-                "
-                ..@@.
-                @.@..
-                "
+    let mut waves = Waves::new(grid, rules);
+    let mut wave = 0;
+    loop {
+        let frame = Frame::new(waves.grid(), waves.accessible());
+        if cli.visualize {
+            // Clear the screen and draw from the top left
+            let mut out = io::stdout().lock();
+            write!(
+                out,
+                "\x1b[2J\x1b[HWave {}: removing {} rolls\n{}",
+                wave,
+                waves.accessible().len(),
+                frame.ansi()
             )
-            .0,
-            vec![
-                vec![false, false, true, true, false],
-                vec![true, false, true, false, false]
-            ]
-        );
-    }
+            .and_then(|_| out.flush())
+            .map_err(|e| format!("failed to draw frame: {}", e))?;
+            thread::sleep(Duration::from_millis(cli.delay));
+        }
+        if let Some(dir) = &cli.frames {
+            let path = dir.join(format!("wave-{:04}.ppm", wave));
+            write_frame(&path, &frame, cli.scale as usize)
+                .map_err(|e| format!("failed to write {}: {}", path.display(), e))?;
+        }
 
-    #[test]
-    fn test_get_neighbors() {
-        let grid = Grid::build(
-            "
-            ..@@.
-            @.@..
-            ",
-        );
-        assert_eq!(grid.get_neighbors(0, 0), 1);
-        assert_eq!(grid.get_neighbors(0, 2), 2);
-        assert_eq!(grid.get_neighbors(1, 3), 3);
+        if waves.next().is_none() {
+            break;
+        }
+        wave += 1;
+    }
+    if let Some(dir) = &cli.frames {
+        println!("{} frames written to {}", wave + 1, dir.display());
     }
+    Ok(())
+}
+
+fn write_frame(path: &Path, frame: &Frame, scale: usize) -> io::Result<()> {
+    let mut out = BufWriter::new(File::create(path)?);
+    frame.write_ppm(&mut out, scale)?;
+    out.flush()
 }
@@ -0,0 +1,184 @@
+/*
+Rather than rescanning the grid after each wave of removals, every roll keeps a
+count of its neighbors. Removing a roll only lowers the counts around it, so the
+next wave is the rolls whose count just dropped below the threshold, and each
+roll is visited a bounded number of times.
+*/
+use common::grid::Grid;
+
+use crate::rules::Target;
+use crate::{Rules, count_neighbors};
+
+/// The waves of rolls removed by repeatedly taking every accessible roll at
+/// once, starting with the rolls accessible in the initial grid
+pub struct Waves<'a> {
+    grid: Grid<bool>,
+    rules: &'a Rules,
+    offsets: Vec<(isize, isize)>,
+    neighbors: Grid<u32>,
+    accessible: Vec<(usize, usize)>,
+}
+
+impl<'a> Waves<'a> {
+    pub fn new(grid: Grid<bool>, rules: &'a Rules) -> Self {
+        let offsets = rules.neighborhood.offsets();
+        let neighbors = Grid::new(
+            grid.width(),
+            grid.positions()
+                .map(|position| count_neighbors(&grid, &offsets, rules.boundary, position))
+                .collect(),
+        );
+        let accessible = grid
+            .iter()
+            .filter(|&(position, &cell)| cell && neighbors[position] < rules.threshold)
+            .map(|(position, _)| position)
+            .collect();
+        Self {
+            grid,
+            rules,
+            offsets,
+            neighbors,
+            accessible,
+        }
+    }
+
+    /// The rolls the next wave will remove
+    pub fn accessible(&self) -> &[(usize, usize)] {
+        &self.accessible
+    }
+
+    /// The grid as left by the waves removed so far
+    pub fn grid(&self) -> &Grid<bool> {
+        &self.grid
+    }
+}
+
+impl Iterator for Waves<'_> {
+    type Item = Vec<(usize, usize)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.accessible.is_empty() {
+            return None;
+        }
+        let wave = std::mem::take(&mut self.accessible);
+        log::debug!("Removing {} rolls", wave.len());
+        for &position in &wave {
+            self.grid[position] = false;
+        }
+
+        // A removed roll was a neighbor of the rolls at the opposite offsets,
+        // which differ from the neighborhood itself when it isn't symmetric
+        let size = (self.grid.height(), self.grid.width());
+        for &position in &wave {
+            for (dr, dc) in &self.offsets {
+                let Some(Target::Cell(neighbor)) =
+                    self.rules.boundary.resolve(size, position, (-dr, -dc))
+                else {
+                    continue;
+                };
+                if !self.grid[neighbor] {
+                    continue;
+                }
+                self.neighbors[neighbor] -= 1;
+                // Only queue a roll as it crosses the threshold, so it's queued once
+                if self.neighbors[neighbor] + 1 == self.rules.threshold {
+                    self.accessible.push(neighbor);
+                }
+            }
+        }
+        Some(wave)
+    }
+}
+
+/// Every wave of a removal, and the wave each roll was removed in
+pub struct Timeline {
+    waves: Vec<Vec<(usize, usize)>>,
+    wave_of: Grid<Option<usize>>,
+}
+
+impl Timeline {
+    pub fn new(grid: &Grid<bool>, rules: &Rules) -> Self {
+        let mut wave_of = Grid::new(grid.width(), vec![None; grid.width() * grid.height()]);
+        let waves: Vec<_> = Waves::new(grid.clone(), rules).collect();
+        for (i, wave) in waves.iter().enumerate() {
+            for &position in wave {
+                wave_of[position] = Some(i);
+            }
+        }
+        Self { waves, wave_of }
+    }
+
+    /// Rolls removed in each wave, in the order they were found
+    pub fn waves(&self) -> &[Vec<(usize, usize)>] {
+        &self.waves
+    }
+
+    pub fn wave_count(&self) -> usize {
+        self.waves.len()
+    }
+
+    pub fn wave_sizes(&self) -> Vec<usize> {
+        self.waves.iter().map(|wave| wave.len()).collect()
+    }
+
+    pub fn removed_count(&self) -> usize {
+        self.waves.iter().map(|wave| wave.len()).sum()
+    }
+
+    /// The wave, counting from 0, that removed the roll at `position`. `None`
+    /// when there's no roll there or it's never removed.
+    pub fn wave_of(&self, position: (usize, usize)) -> Option<usize> {
+        self.wave_of.get(position.0, position.1).copied().flatten()
+    }
+
+    /// The wave of every cell as comma separated rows: the wave number for
+    /// removed rolls, `@` for rolls that are never removed and nothing for
+    /// cells without a roll
+    pub fn export(&self, grid: &Grid<bool>) -> String {
+        let mut out = String::new();
+        for (row_idx, row) in grid.rows().enumerate() {
+            let cells: Vec<String> = row
+                .iter()
+                .enumerate()
+                .map(|(col_idx, &cell)| match self.wave_of((row_idx, col_idx)) {
+                    Some(wave) => wave.to_string(),
+                    None if cell => "@".to_string(),
+                    None => String::new(),
+                })
+                .collect();
+            out.push_str(&cells.join(","));
+            out.push('\n');
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::build_grid;
+
+    #[test]
+    fn test_timeline() {
+        let grid = build_grid(
+            "
+            @@@@@
+            @@@@@
+            @@@@@
+            ..@..
+            ",
+        )
+        .unwrap();
+        let timeline = Timeline::new(&grid, &Rules::default());
+        assert_eq!(timeline.wave_sizes(), vec![5, 2, 4, 4, 1]);
+        assert_eq!(timeline.wave_count(), 5);
+        assert_eq!(timeline.removed_count(), 16);
+        assert_eq!(timeline.wave_of((0, 0)), Some(0));
+        assert_eq!(timeline.wave_of((1, 2)), Some(4));
+        assert_eq!(timeline.wave_of((3, 0)), None);
+        assert_eq!(
+            timeline.export(&grid),
+            "0,2,3,2,0\n1,3,4,3,1\n0,2,3,2,0\n,,0,,\n"
+        );
+    }
+}
@@ -0,0 +1,198 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use clap::ValueEnum;
+
+/// Which cells around a roll count as its neighbors
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Neighborhood {
+    /// The four cells sharing an edge
+    VonNeumann,
+    /// The eight surrounding cells, as in the puzzle
+    Moore,
+    /// Every cell within this many rows and columns
+    Radius(usize),
+    /// Cells at these `(row, column)` offsets
+    Custom(Vec<(isize, isize)>),
+}
+
+impl Neighborhood {
+    /// Offsets from a cell to each of its neighbors
+    pub fn offsets(&self) -> Vec<(isize, isize)> {
+        match self {
+            Neighborhood::VonNeumann => vec![(-1, 0), (0, -1), (0, 1), (1, 0)],
+            Neighborhood::Moore => Neighborhood::Radius(1).offsets(),
+            Neighborhood::Radius(k) => {
+                let k = *k as isize;
+                (-k..=k)
+                    .flat_map(|dr| (-k..=k).map(move |dc| (dr, dc)))
+                    .filter(|&offset| offset != (0, 0))
+                    .collect()
+            }
+            Neighborhood::Custom(offsets) => offsets.clone(),
+        }
+    }
+}
+
+impl Display for Neighborhood {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Neighborhood::VonNeumann => write!(f, "von-neumann"),
+            Neighborhood::Moore => write!(f, "moore"),
+            Neighborhood::Radius(k) => write!(f, "radius:{}", k),
+            Neighborhood::Custom(offsets) => {
+                let offsets: Vec<String> = offsets
+                    .iter()
+                    .map(|(dr, dc)| format!("{},{}", dr, dc))
+                    .collect();
+                write!(f, "offsets:{}", offsets.join(";"))
+            }
+        }
+    }
+}
+
+/// Parses `von-neumann`, `moore`, `radius:K` and `offsets:DR,DC;DR,DC;...`
+impl FromStr for Neighborhood {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, arg) = s.split_once(':').unwrap_or((s, ""));
+        match (name, arg) {
+            ("von-neumann", "") => Ok(Neighborhood::VonNeumann),
+            ("moore", "") => Ok(Neighborhood::Moore),
+            ("radius", k) => k
+                .trim()
+                .parse()
+                .map(Neighborhood::Radius)
+                .map_err(|e| format!("invalid radius {:?}: {}", k, e)),
+            ("offsets", offsets) => offsets
+                .split(';')
+                .map(|offset| {
+                    let invalid = || format!("invalid offset {:?}, expected DR,DC", offset);
+                    let (dr, dc) = offset.split_once(',').ok_or_else(invalid)?;
+                    let dr = dr.trim().parse().map_err(|_| invalid())?;
+                    let dc = dc.trim().parse().map_err(|_| invalid())?;
+                    Ok((dr, dc))
+                })
+                .collect::<Result<_, _>>()
+                .map(Neighborhood::Custom),
+            _ => Err(format!(
+                "unknown neighborhood {:?}, expected von-neumann, moore, radius:K or offsets:DR,DC;...",
+                s
+            )),
+        }
+    }
+}
+
+/// What lies beyond the edges of the grid
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Boundary {
+    /// Nothing, neighbors outside the grid are ignored
+    #[default]
+    Clamped,
+    /// The opposite edge, as if the grid were a torus
+    Wrap,
+    /// Rolls that are never removed
+    Occupied,
+}
+
+impl Display for Boundary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Boundary::Clamped => "clamped",
+            Boundary::Wrap => "wrap",
+            Boundary::Occupied => "occupied",
+        })
+    }
+}
+
+/// Where a neighbor offset lands
+pub(crate) enum Target {
+    Cell((usize, usize)),
+    /// Beyond the edge, in a cell that is always occupied
+    Outside,
+}
+
+impl Boundary {
+    /// Where `offset` from `(row, col)` lands in a grid of `height` rows and
+    /// `width` columns, `None` when it lands nowhere
+    pub(crate) fn resolve(
+        self,
+        (height, width): (usize, usize),
+        (row, col): (usize, usize),
+        (dr, dc): (isize, isize),
+    ) -> Option<Target> {
+        let inside = |pos: usize, delta: isize, len: usize| {
+            pos.checked_add_signed(delta).filter(|&p| p < len)
+        };
+        match (inside(row, dr, height), inside(col, dc, width)) {
+            (Some(r), Some(c)) => Some(Target::Cell((r, c))),
+            _ => match self {
+                Boundary::Clamped => None,
+                Boundary::Occupied => Some(Target::Outside),
+                Boundary::Wrap => {
+                    let wrap = |pos: usize, delta: isize, len: usize| {
+                        (pos as isize + delta).rem_euclid(len as isize) as usize
+                    };
+                    Some(Target::Cell((wrap(row, dr, height), wrap(col, dc, width))))
+                }
+            },
+        }
+    }
+}
+
+/// When a roll can be reached by a forklift
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rules {
+    pub neighborhood: Neighborhood,
+    /// Rolls with fewer neighboring rolls than this are accessible
+    pub threshold: u32,
+    pub boundary: Boundary,
+}
+
+impl Default for Rules {
+    /// The rules from the puzzle
+    fn default() -> Self {
+        Self {
+            neighborhood: Neighborhood::Moore,
+            threshold: 4,
+            boundary: Boundary::Clamped,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_neighborhood_offsets() {
+        assert_eq!(Neighborhood::VonNeumann.offsets().len(), 4);
+        assert_eq!(Neighborhood::Moore.offsets().len(), 8);
+        assert_eq!(Neighborhood::Radius(2).offsets().len(), 24);
+    }
+
+    #[test]
+    fn test_neighborhood_from_str() {
+        assert_eq!("moore".parse(), Ok(Neighborhood::Moore));
+        assert_eq!("radius:3".parse(), Ok(Neighborhood::Radius(3)));
+        let custom = Neighborhood::Custom(vec![(-1, 0), (2, -2)]);
+        assert_eq!("offsets:-1,0;2,-2".parse(), Ok(custom.clone()));
+        assert_eq!(custom.to_string().parse(), Ok(custom));
+        assert!("offsets:1".parse::<Neighborhood>().is_err());
+        assert!("hex".parse::<Neighborhood>().is_err());
+    }
+
+    #[test]
+    fn test_boundary_resolve() {
+        let resolve = |boundary: Boundary, offset| match boundary.resolve((3, 4), (0, 3), offset) {
+            Some(Target::Cell(position)) => Some(Some(position)),
+            Some(Target::Outside) => Some(None),
+            None => None,
+        };
+        assert_eq!(resolve(Boundary::Clamped, (1, -1)), Some(Some((1, 2))));
+        assert_eq!(resolve(Boundary::Clamped, (-1, 0)), None);
+        assert_eq!(resolve(Boundary::Occupied, (0, 1)), Some(None));
+        assert_eq!(resolve(Boundary::Wrap, (-1, 1)), Some(Some((2, 0))));
+    }
+}
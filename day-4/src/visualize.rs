@@ -0,0 +1,107 @@
+use std::io::{self, Write};
+
+use common::grid::Grid;
+
+const RESET: &str = "\x1b[0m";
+const ROLL: &str = "\x1b[1;37m";
+const REMOVING: &str = "\x1b[1;31m";
+const EMPTY: &str = "\x1b[2m";
+
+const ROLL_RGB: [u8; 3] = [60, 60, 70];
+const REMOVING_RGB: [u8; 3] = [220, 40, 40];
+const EMPTY_RGB: [u8; 3] = [240, 235, 220];
+
+/// A snapshot of the grid, with the rolls about to be removed highlighted
+pub struct Frame<'a> {
+    grid: &'a Grid<bool>,
+    removing: Grid<bool>,
+}
+
+impl<'a> Frame<'a> {
+    pub fn new(grid: &'a Grid<bool>, removing: &[(usize, usize)]) -> Self {
+        let mut mask = Grid::new(grid.width(), vec![false; grid.width() * grid.height()]);
+        for &position in removing {
+            mask[position] = true;
+        }
+        Self {
+            grid,
+            removing: mask,
+        }
+    }
+
+    /// The grid colored for a terminal, rolls about to be removed in red
+    pub fn ansi(&self) -> String {
+        let mut out = String::new();
+        for (row_idx, row) in self.grid.rows().enumerate() {
+            let mut color = "";
+            for (col_idx, &cell) in row.iter().enumerate() {
+                let (next, c) = match (cell, self.removing[(row_idx, col_idx)]) {
+                    (true, true) => (REMOVING, '@'),
+                    (true, false) => (ROLL, '@'),
+                    (false, _) => (EMPTY, '.'),
+                };
+                // Only switch color between runs of different cells
+                if next != color {
+                    out.push_str(next);
+                    color = next;
+                }
+                out.push(c);
+            }
+            out.push_str(RESET);
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Write the grid as a binary PPM image, each cell a `scale` pixel square
+    pub fn write_ppm(&self, mut out: impl Write, scale: usize) -> io::Result<()> {
+        let (width, height) = (self.grid.width() * scale, self.grid.height() * scale);
+        write!(out, "P6\n{} {}\n255\n", width, height)?;
+        for (row_idx, row) in self.grid.rows().enumerate() {
+            let mut line = Vec::with_capacity(width * 3);
+            for (col_idx, &cell) in row.iter().enumerate() {
+                let rgb = match (cell, self.removing[(row_idx, col_idx)]) {
+                    (true, true) => REMOVING_RGB,
+                    (true, false) => ROLL_RGB,
+                    (false, _) => EMPTY_RGB,
+                };
+                for _ in 0..scale {
+                    line.extend_from_slice(&rgb);
+                }
+            }
+            for _ in 0..scale {
+                out.write_all(&line)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::build_grid;
+
+    #[test]
+    fn test_ansi() {
+        let grid = build_grid("@@.\n.@@").unwrap();
+        let frame = Frame::new(&grid, &[(0, 0)]);
+        assert_eq!(
+            frame.ansi(),
+            format!("{REMOVING}@{ROLL}@{EMPTY}.{RESET}\n{EMPTY}.{ROLL}@@{RESET}\n")
+        );
+    }
+
+    #[test]
+    fn test_ppm() {
+        let grid = build_grid("@.").unwrap();
+        let frame = Frame::new(&grid, &[(0, 0)]);
+        let mut out = Vec::new();
+        frame.write_ppm(&mut out, 2).unwrap();
+
+        let header = b"P6\n4 2\n255\n";
+        assert_eq!(&out[..header.len()], header);
+        let row = [REMOVING_RGB, REMOVING_RGB, EMPTY_RGB, EMPTY_RGB].concat();
+        assert_eq!(&out[header.len()..], [row.clone(), row].concat());
+    }
+}
@@ -0,0 +1,240 @@
+use common::{ParseError, ParseErrorKind, Solution};
+
+// https://adventofcode.com/2025/day/5
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Input = IngredientsList;
+    type Answer1 = usize;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        IngredientsList::build(input)
+    }
+
+    fn part1(ingredients: &Self::Input) -> Self::Answer1 {
+        ingredients.fresh_count()
+    }
+
+    fn part2(ingredients: &Self::Input) -> Self::Answer2 {
+        ingredients.possibly_fresh_count()
+    }
+}
+
+pub struct IngredientsList {
+    fresh: Vec<Range>,
+    available: Vec<Ingredient>,
+}
+
+impl IngredientsList {
+    pub fn build(input: impl AsRef<str>) -> Result<Self, ParseError> {
+        let input = input.as_ref();
+        let mut split_input = input.trim().split("\n\n");
+        let fresh_ranges_str = split_input.next().unwrap_or_default();
+        let parse_id = |s: &str| {
+            s.parse::<u64>()
+                .map_err(|e| ParseError::new(ParseErrorKind::InvalidNumber(e), input, s))
+        };
+
+        log::info!("Storing ranges...");
+        let mut unmerged_ranges = Vec::new();
+        for fresh_range in fresh_ranges_str.split("\n").map(|l| l.trim()) {
+            let (start_str, end_str) = fresh_range.split_once('-').ok_or_else(|| {
+                ParseError::new(
+                    ParseErrorKind::Missing {
+                        expected: "`-` between start and end of range",
+                    },
+                    input,
+                    fresh_range,
+                )
+            })?;
+            let start = parse_id(start_str)?;
+            let end = parse_id(end_str)?;
+            if start > end {
+                return Err(ParseError::new(
+                    ParseErrorKind::Unexpected {
+                        expected: "a range ending at or after its start",
+                    },
+                    input,
+                    fresh_range,
+                ));
+            }
+            unmerged_ranges.push(Range { start, end });
+        }
+
+        log::info!("Sorting ranges...");
+        unmerged_ranges.sort_by_key(|r| r.start);
+
+        log::info!("Merging ranges...");
+        let mut fresh_ranges = Vec::<Range>::new();
+        for range in unmerged_ranges {
+            if let Some(last) = fresh_ranges.last_mut() {
+                if range.start <= last.end {
+                    last.end = last.end.max(range.end);
+                } else {
+                    fresh_ranges.push(range)
+                }
+            } else {
+                fresh_ranges.push(range);
+            }
+        }
+
+        let available_ids_str = split_input.next();
+        let available = match available_ids_str {
+            Some(available_ids_str) => {
+                log::info!("Storing available...");
+                let mut available = available_ids_str
+                    .split('\n')
+                    .map(|l| {
+                        Ok(Ingredient {
+                            id: parse_id(l.trim())?,
+                            state: State::Spoiled,
+                        })
+                    })
+                    .collect::<Result<Vec<Ingredient>, ParseError>>()?;
+
+                log::info!("Sorting available...");
+                available.sort_by_key(|i| i.id);
+
+                log::info!("Update available state from inventory...");
+                let mut range_idx = 0;
+                for i in &mut available {
+                    while range_idx < fresh_ranges.len() {
+                        let r = fresh_ranges.get(range_idx).expect("Failed to get range");
+                        if i.id < r.start {
+                            log::debug!("id {} less than range start {}, not fresh", i.id, r.start);
+                            break;
+                        } else if i.id > r.end {
+                            log::debug!(
+                                "id {} greater than range end {}, need next range",
+                                i.id,
+                                r.end
+                            );
+                            range_idx += 1;
+                            continue;
+                        } else {
+                            log::debug!(
+                                "id {} less than range start {} and greater than range end {}, it's fresh!",
+                                i.id,
+                                r.start,
+                                r.end
+                            );
+                            i.state = State::Fresh;
+                            break;
+                        }
+                    }
+                }
+                available
+            }
+            None => Vec::new(),
+        };
+
+        Ok(Self {
+            fresh: fresh_ranges,
+            available,
+        })
+    }
+
+    /// How many ingredients are actually fresh, as per the fresh ranges and available list.
+    /// Nothing is fresh when the input only lists the fresh ranges.
+    pub fn fresh_count(&self) -> usize {
+        self.available
+            .iter()
+            .filter(|i| matches!(i.state, State::Fresh))
+            .count()
+    }
+
+    /// How many ingredients could be fresh, as per the fresh ranges
+    pub fn possibly_fresh_count(&self) -> u64 {
+        self.fresh.iter().map(|r| r.end - r.start + 1).sum()
+    }
+}
+
+struct Ingredient {
+    id: u64,
+    state: State,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+enum State {
+    Fresh,
+    Spoiled,
+}
+
+struct Range {
+    start: u64,
+    end: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pt_1_ex() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        assert_eq!(
+            IngredientsList::build(
+                "
+                3-5
+                10-14
+                16-20
+                12-18
+
+                1
+                5
+                8
+                11
+                17
+                32
+                ",
+            )
+            .unwrap()
+            .fresh_count(),
+            3
+        )
+    }
+    #[test]
+    fn test_pt_2_ex() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        assert_eq!(
+            IngredientsList::build(
+                "
+                3-5
+                10-14
+                16-20
+                12-18
+                ",
+            )
+            .unwrap()
+            .possibly_fresh_count(),
+            14
+        )
+    }
+
+    #[test]
+    fn test_build_invalid_range() {
+        let Err(err) = IngredientsList::build("3-5\n10:14\n\n1\n") else {
+            panic!("Expected a parse error");
+        };
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, "10:14"));
+    }
+
+    #[test]
+    fn test_build_reversed_range() {
+        let Err(err) = IngredientsList::build("3-5\n5-3\n\n4") else {
+            panic!("Expected a parse error");
+        };
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, "5-3"));
+        assert!(matches!(err.kind, ParseErrorKind::Unexpected { .. }));
+    }
+
+    #[test]
+    fn test_parse_without_available() {
+        let ingredients = Day5::parse("3-5\n10-14\n").unwrap();
+        assert_eq!(Day5::part1(&ingredients), 0);
+        assert_eq!(Day5::part2(&ingredients), 8);
+    }
+}
@@ -0,0 +1,258 @@
+use common::{ParseError, ParseErrorKind, Solution};
+
+// https://adventofcode.com/2025/day/6
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Input = Worksheet;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Worksheet::build(input)
+    }
+
+    /// Grand total of the worksheet, read row by row
+    fn part1(worksheet: &Self::Input) -> Self::Answer1 {
+        worksheet.grand_total(MathStyle::Traditional)
+    }
+
+    /// Grand total of the worksheet, read column by column
+    fn part2(worksheet: &Self::Input) -> Self::Answer2 {
+        worksheet.grand_total(MathStyle::Cephalopod)
+    }
+}
+
+pub fn process(input: impl AsRef<str>, style: MathStyle) -> Result<u64, ParseError> {
+    Ok(Worksheet::build(input)?.grand_total(style))
+}
+
+/// The problems on a worksheet, as read by both humans and cephalopods
+pub struct Worksheet {
+    traditional: Vec<Problem>,
+    cephalopod: Vec<Problem>,
+}
+
+impl Worksheet {
+    pub fn build(input: impl AsRef<str>) -> Result<Self, ParseError> {
+        let input = input.as_ref();
+        let lines = input
+            .split('\n')
+            .filter(|l| !l.trim().is_empty())
+            .collect::<Vec<&str>>();
+        if lines.is_empty() {
+            return Err(ParseError::new(
+                ParseErrorKind::Missing {
+                    expected: "worksheet rows",
+                },
+                input,
+                &input[input.len()..],
+            ));
+        }
+        Ok(Self {
+            traditional: problems(input, &lines, MathStyle::Traditional)?,
+            cephalopod: problems(input, &lines, MathStyle::Cephalopod)?,
+        })
+    }
+
+    pub fn grand_total(&self, style: MathStyle) -> u64 {
+        let problems = match style {
+            MathStyle::Traditional => &self.traditional,
+            MathStyle::Cephalopod => &self.cephalopod,
+        };
+        problems.iter().map(|p| p.solve()).sum()
+    }
+}
+
+fn problems(input: &str, lines: &[&str], style: MathStyle) -> Result<Vec<Problem>, ParseError> {
+    let mut problems = Vec::new();
+    let mut values = Vec::new();
+
+    match style {
+        MathStyle::Traditional => {
+            for line in lines {
+                for (i, val) in line.split_whitespace().enumerate() {
+                    // Buildout vector to hold values (only on first pass)
+                    if i == values.len() {
+                        values.push(Vec::new());
+                    }
+
+                    // Get mutable vector to store values
+                    let i_values = values
+                        .get_mut(i)
+                        .unwrap_or_else(|| panic!("Index {} not set in values vector", i));
+
+                    // Attempt to parse column value...
+                    if let Ok(val) = val.parse::<u64>() {
+                        // if successful, place in vector of values
+                        i_values.push(val);
+                    } else {
+                        // If fail, assuming it's an operator (and thus end of lines)
+                        let operator = Operator::from_str(val).ok_or_else(|| {
+                            ParseError::new(
+                                ParseErrorKind::Unexpected {
+                                    expected: "a number or an operator (`+` or `*`)",
+                                },
+                                input,
+                                val,
+                            )
+                        })?;
+                        problems.push(Problem {
+                            values: i_values.to_vec(),
+                            operator,
+                        });
+                    };
+                }
+            }
+        }
+        MathStyle::Cephalopod => {
+            /*
+                Rather than line-by-line, we iterate column by column (per character).
+                Lines that are shorter than the others are treated as padded with spaces.
+            */
+            let num_cols = lines
+                .iter()
+                .map(|l| l.chars().count())
+                .max()
+                .unwrap_or_default();
+
+            for col_idx in 0..num_cols {
+                // Create an empty value for each column, remembering where its
+                // characters came from in case it can't be parsed
+                let mut col_val = String::new();
+                let mut col_chars = Vec::new();
+
+                // Populate value from line
+                for line in lines {
+                    let Some((byte_idx, col_char)) = line.char_indices().nth(col_idx) else {
+                        continue;
+                    };
+                    log::debug!("Got char \"{}\" from col {} of {}", col_char, col_idx, line);
+
+                    // If operator, create problem with empty values
+                    if let Some(operator) = Operator::from_str(col_char.to_string()) {
+                        log::debug!("Creating problem with {} operator", col_char);
+                        problems.push(Problem {
+                            values: Vec::new(),
+                            operator,
+                        });
+                    } else if let ' ' = col_char {
+                        // Otherwise, must be a part of the value
+                    } else {
+                        log::trace!(
+                            "Pushing \"{}\" into value \"{}\" for col {}",
+                            col_char,
+                            col_val,
+                            col_idx
+                        );
+                        col_val.push(col_char);
+                        col_chars.push(&line[byte_idx..byte_idx + col_char.len_utf8()]);
+                    }
+                }
+
+                log::debug!("Inserting value {}", col_val);
+                if col_val.trim().is_empty() {
+                    log::debug!("Empty column");
+                    continue;
+                }
+
+                // After all the lines, we can now put the value into the problem
+                let Some(problem) = problems.last_mut() else {
+                    return Err(ParseError::new(
+                        ParseErrorKind::Missing {
+                            expected: "an operator at the start of the problem",
+                        },
+                        input,
+                        col_chars[0],
+                    ));
+                };
+                let value = col_val.trim().parse::<u64>().map_err(|e| {
+                    let culprit = col_chars
+                        .iter()
+                        .find(|c| !c.chars().all(|c| c.is_ascii_digit()))
+                        .unwrap_or(&col_chars[0]);
+                    ParseError::new(ParseErrorKind::InvalidNumber(e), input, culprit)
+                })?;
+                problem.values.push(value);
+            }
+        }
+    }
+
+    Ok(problems)
+}
+
+struct Problem {
+    values: Vec<u64>,
+    operator: Operator,
+}
+
+impl Problem {
+    fn solve(&self) -> u64 {
+        match self.operator {
+            Operator::Added => self.values.iter().sum::<u64>(),
+            Operator::Multiplied => self.values.iter().product(),
+        }
+    }
+}
+
+enum Operator {
+    Added,
+    Multiplied,
+}
+
+impl Operator {
+    fn from_str(val: impl AsRef<str>) -> Option<Self> {
+        let v = val.as_ref();
+        match v {
+            "*" => Some(Operator::Multiplied),
+            "+" => Some(Operator::Added),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+pub enum MathStyle {
+    Traditional,
+    Cephalopod,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pt_1_ex() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let input = "
+        123 328  51 64 
+         45 64  387 23 
+          6 98  215 314
+        *   +   *   +  
+        ";
+        assert_eq!(process(input, MathStyle::Traditional).unwrap(), 4277556)
+    }
+
+    #[test]
+    fn test_pt_2_ex() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let input = "
+123 328  51 64 
+ 45 64  387 23 
+  6 98  215 314
+*   +   *   +  
+        ";
+        assert_eq!(process(input, MathStyle::Cephalopod).unwrap(), 3263827)
+    }
+
+    #[test]
+    fn test_invalid_operator() {
+        let input = "123 328\n 45 64 \n*   -  \n";
+        let Err(err) = Worksheet::build(input) else {
+            panic!("Expected a parse error");
+        };
+        assert_eq!((err.line, err.column, err.text.as_str()), (3, 5, "-"));
+    }
+}